@@ -10,6 +10,10 @@
 //! to use these types if you're writing a library that you also want to be
 //! runtime-agnostic.
 
+mod dyn_job;
 mod job;
+mod job_set;
 
-pub use job::Job;
+pub use dyn_job::DynJob;
+pub use job::{Job, JobFuture};
+pub use job_set::JobSet;
@@ -0,0 +1,215 @@
+use std::fmt;
+use std::future::IntoFuture;
+use std::sync::Arc;
+use std::time::Duration;
+
+use futures::future::{self, BoxFuture, Either};
+use futures::FutureExt;
+
+use super::{Job, JobFuture};
+use crate::runtime::{JobHandle, Runtime};
+use crate::{Error, JobStatus, Monitor, Result};
+
+/// A job of any runtime.
+///
+/// A `DynJob` can be created from any [`Job`] using [`From`], and it can be
+/// waited on just like the original job. This allows jobs of different
+/// runtimes to be stored together, for example in a `Girlboss<K, DynJob>`,
+/// which can start jobs of any runtime.
+///
+/// # Examples
+///
+/// ```
+/// # #[tokio::main]
+/// # async fn main() {
+/// use girlboss::common::DynJob;
+/// use girlboss::runtime::Tokio;
+/// use girlboss::Girlboss;
+///
+/// let mut manager = Girlboss::<String, DynJob>::new();
+/// manager
+///     .start::<Tokio, _, _>("import", |mon| async move {
+///         write!(mon, "Imported");
+///     })
+///     .unwrap();
+///
+/// let job = manager.get("import").unwrap();
+/// job.wait().await.unwrap();
+/// assert_eq!(job.status().message(), "Imported");
+/// # }
+/// ```
+pub struct DynJob {
+    handle: Arc<dyn ErasedHandle>,
+    monitor: Monitor,
+}
+
+/// A [`JobHandle`] with its runtime erased.
+trait ErasedHandle: Send + Sync {
+    /// Waits for the job to finish.
+    fn wait(&self) -> BoxFuture<'_, ()>;
+
+    /// Like [`wait`](Self::wait), but owns the handle.
+    fn wait_owned(self: Arc<Self>) -> BoxFuture<'static, ()>;
+
+    /// Waits until `duration` has elapsed, using the job's runtime.
+    fn sleep(&self, duration: Duration) -> BoxFuture<'static, ()>;
+}
+
+/// The join handle of a [`Job`] of the runtime `R`.
+struct Erased<R: Runtime>(Arc<R::JobHandle>);
+
+impl<R: Runtime> ErasedHandle for Erased<R> {
+    fn wait(&self) -> BoxFuture<'_, ()> {
+        self.0.wait().boxed()
+    }
+
+    fn wait_owned(self: Arc<Self>) -> BoxFuture<'static, ()> {
+        async move { self.0.wait().await }.boxed()
+    }
+
+    fn sleep(&self, duration: Duration) -> BoxFuture<'static, ()> {
+        R::sleep(duration).boxed()
+    }
+}
+
+impl<R: Runtime> From<Job<R>> for DynJob {
+    fn from(job: Job<R>) -> Self {
+        DynJob {
+            handle: Arc::new(Erased::<R>(job.handle)),
+            monitor: job.monitor,
+        }
+    }
+}
+
+impl DynJob {
+    /// Waits for this job to finish. See [`Job::wait`].
+    pub async fn wait(&self) -> Result<()> {
+        self.handle.wait().await;
+        if self.monitor.succeeded() {
+            Ok(())
+        } else {
+            Err(Error::JobFailed)
+        }
+    }
+
+    /// Waits for this job to finish, but gives up after `timeout`. See
+    /// [`Job::wait_timeout`].
+    pub async fn wait_timeout(&self, timeout: Duration) -> Result<()> {
+        let wait = std::pin::pin!(self.wait());
+        match future::select(wait, self.handle.sleep(timeout)).await {
+            Either::Left((result, _)) => result,
+            Either::Right(((), _)) => Err(Error::Timeout),
+        }
+    }
+
+    /// Returns a future that resolves to the same result as
+    /// [`wait`](Self::wait) once this job finishes. See [`Job::future`].
+    pub fn future(&self) -> JobFuture {
+        JobFuture::new(self.monitor.finished(), self.handle.clone().wait_owned())
+    }
+
+    /// Alias of
+    /// <code>self.monitor().[wait_blocking](Monitor::wait_blocking)()</code>.
+    pub fn wait_blocking(&self) -> Result<()> {
+        self.monitor.wait_blocking()
+    }
+
+    /// Alias of
+    /// <code>self.monitor().[wait_blocking_timeout](Monitor::wait_blocking_timeout)(timeout)</code>.
+    pub fn wait_blocking_timeout(&self, timeout: Duration) -> Result<()> {
+        self.monitor.wait_blocking_timeout(timeout)
+    }
+
+    /// Alias of <code>self.monitor().[cancel](Monitor::cancel)()</code>.
+    pub fn cancel(&self) {
+        self.monitor.cancel()
+    }
+
+    /// Alias of
+    /// <code>self.monitor().[on_finish](Monitor::on_finish)(callback)</code>.
+    pub fn on_finish(&self, callback: impl FnOnce(bool, JobStatus) + Send + 'static) {
+        self.monitor.on_finish(callback)
+    }
+}
+
+/// Methods to check the status of a job.
+impl DynJob {
+    /// Returns a reference to this job's [`Monitor`].
+    pub fn monitor(&self) -> &Monitor {
+        &self.monitor
+    }
+
+    /// Alias of <code>self.monitor().[status](Monitor::status)()</code>.
+    pub fn status(&self) -> JobStatus {
+        self.monitor.status()
+    }
+
+    /// Alias of <code>self.monitor().[outcome](Monitor::outcome)()</code>.
+    pub fn outcome(&self) -> Option<bool> {
+        self.monitor.outcome()
+    }
+
+    /// Alias of
+    /// <code>self.monitor().[is_finished](Monitor::is_finished)()</code>.
+    pub fn is_finished(&self) -> bool {
+        self.monitor.is_finished()
+    }
+
+    /// Alias of <code>self.monitor().[succeeded](Monitor::succeeded)()</code>.
+    pub fn succeeded(&self) -> bool {
+        self.monitor.succeeded()
+    }
+}
+
+impl IntoFuture for DynJob {
+    type Output = Result<()>;
+    type IntoFuture = JobFuture;
+
+    fn into_future(self) -> JobFuture {
+        self.future()
+    }
+}
+
+impl IntoFuture for &DynJob {
+    type Output = Result<()>;
+    type IntoFuture = JobFuture;
+
+    fn into_future(self) -> JobFuture {
+        self.future()
+    }
+}
+
+impl AsRef<Monitor> for DynJob {
+    fn as_ref(&self) -> &Monitor {
+        &self.monitor
+    }
+}
+
+impl Clone for DynJob {
+    fn clone(&self) -> Self {
+        Self {
+            handle: self.handle.clone(),
+            monitor: self.monitor.clone(),
+        }
+    }
+}
+
+impl PartialEq for DynJob {
+    fn eq(&self, other: &Self) -> bool {
+        self.monitor == other.monitor
+    }
+}
+
+impl Eq for DynJob {}
+
+impl fmt::Debug for DynJob {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("DynJob").field(&self.monitor).finish()
+    }
+}
+
+impl fmt::Pointer for DynJob {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.monitor.fmt(f)
+    }
+}
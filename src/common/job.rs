@@ -1,9 +1,15 @@
 use std::fmt;
-use std::future::Future;
+use std::future::{Future, IntoFuture};
+use std::pin::Pin;
 use std::sync::Arc;
+use std::task::{Context, Poll};
+use std::time::Duration;
 
-use crate::runtime::{JobHandle, Runtime, Spawnable};
-use crate::{Error, JobReturnStatus, JobStatus, Monitor, Result};
+use futures::future::{BoxFuture, FusedFuture, Shared};
+use futures::FutureExt;
+
+use crate::runtime::{self, Adoptable, JobHandle, Runtime, SpawnOn, Spawnable};
+use crate::{Error, Finished, JobReturnStatus, JobStatus, Monitor, Result};
 
 /// A job, either running or finished.
 ///
@@ -17,8 +23,8 @@ use crate::{Error, JobReturnStatus, JobStatus, Monitor, Result};
 /// [`Monitor`] is always `Send + Sync`, whereas, if the async-runtime-specific
 /// join handle is not `Send` or `Sync`, then neither is the `Job`.
 pub struct Job<R: Runtime> {
-    handle: Arc<R::JobHandle>,
-    monitor: Monitor,
+    pub(super) handle: Arc<R::JobHandle>,
+    pub(super) monitor: Monitor,
 }
 
 impl<R: Runtime> Job<R> {
@@ -53,13 +59,179 @@ impl<R: Runtime> Job<R> {
         F: FnOnce(Monitor) -> Fut,
         Fut: Spawnable<R>,
         <Fut as Future>::Output: Into<JobReturnStatus>,
+    {
+        Job::start_with_monitor(Monitor::starting(), func, None)
+    }
+
+    /// Like [`start`](Self::start), but spawns the job onto `spawner` instead
+    /// of the current runtime.
+    ///
+    /// With Tokio, the spawner can be a runtime [`Handle`], or a [`LocalSet`]
+    /// for jobs that aren't `Send`. With actix-rt, the spawner can be an
+    /// [`ArbiterHandle`], in which case the job must be `Send`.
+    ///
+    /// # Examples
+    ///
+    /// Running a job on a dedicated runtime:
+    ///
+    /// ```
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// use girlboss::tokio::Job;
+    ///
+    /// let background = tokio::runtime::Runtime::new().unwrap();
+    /// let job = Job::start_on(background.handle(), |mon| async move {
+    ///     write!(mon, "Running in the background");
+    /// });
+    /// job.wait().await.unwrap();
+    /// assert_eq!(job.status().message(), "Running in the background");
+    /// # background.shutdown_background();
+    /// # }
+    /// ```
+    ///
+    /// [`Handle`]: https://docs.rs/tokio/1/tokio/runtime/struct.Handle.html
+    /// [`LocalSet`]: https://docs.rs/tokio/1/tokio/task/struct.LocalSet.html
+    /// [`ArbiterHandle`]: https://docs.rs/actix-rt/2/actix_rt/struct.ArbiterHandle.html
+    pub fn start_on<S, F, Fut>(spawner: &S, func: F) -> Self
+    where
+        S: SpawnOn<R, Fut>,
+        F: FnOnce(Monitor) -> Fut,
+        Fut: Future,
+        <Fut as Future>::Output: Into<JobReturnStatus>,
     {
         let monitor = Monitor::starting();
         let fut = func(monitor.clone());
-        let handle = Arc::new(fut.spawn(monitor.clone()));
+        let handle = Arc::new(spawner.spawn_on(fut, monitor.clone()));
         Job { handle, monitor }
     }
 
+    /// Creates a job that tracks a task that was spawned outside of this
+    /// crate, such as a Tokio `JoinHandle` handed over by a library. See
+    /// [`Adoptable`] for the supported handles.
+    ///
+    /// The monitor should come from [`Monitor::detached`], so that the task
+    /// can report its progress through it. The job finishes once the task
+    /// does, with the task's output as its [`JobReturnStatus`]. If the task
+    /// panics, then the job fails with the status message "The job panicked".
+    /// Cancelling the job cancels the task as well.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `monitor` already belongs to a job.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// use girlboss::Monitor;
+    /// use girlboss::tokio::Job;
+    ///
+    /// let monitor = Monitor::detached();
+    /// let mon = monitor.clone();
+    /// let handle = tokio::spawn(async move {
+    ///     write!(mon, "Working");
+    ///     "Done elsewhere"
+    /// });
+    ///
+    /// let job = Job::adopt(handle, monitor);
+    /// job.wait().await.unwrap();
+    /// assert_eq!(job.status().message(), "Done elsewhere");
+    /// # }
+    /// ```
+    pub fn adopt(handle: impl Adoptable<R>, monitor: Monitor) -> Self {
+        assert!(
+            !monitor.is_attached(),
+            "cannot adopt a task with a monitor that already belongs to a job",
+        );
+        Job::adopt_with_monitor(monitor, handle)
+    }
+
+    /// Adopts a task that reports to an existing, not yet started monitor.
+    pub(crate) fn adopt_with_monitor(monitor: Monitor, handle: impl Adoptable<R>) -> Self {
+        Job {
+            handle: Arc::new(handle.adopt(monitor.clone())),
+            monitor,
+        }
+    }
+
+    /// Starts a new job that reports to an existing, not yet started monitor,
+    /// using `spawner` if there is one.
+    pub(crate) fn start_with_monitor<F, Fut>(
+        monitor: Monitor,
+        func: F,
+        spawner: Option<&R::Spawner>,
+    ) -> Self
+    where
+        F: FnOnce(Monitor) -> Fut,
+        Fut: Spawnable<R>,
+        <Fut as Future>::Output: Into<JobReturnStatus>,
+    {
+        let fut = func(monitor.clone());
+        let handle = match spawner {
+            Some(spawner) => fut.spawn_on(spawner, monitor.clone()),
+            None => fut.spawn(monitor.clone()),
+        };
+        Job {
+            handle: Arc::new(handle),
+            monitor,
+        }
+    }
+
+    /// Creates and starts a new job that runs a blocking function.
+    ///
+    /// The function runs on the runtime's thread pool for blocking tasks, so
+    /// it may do CPU-heavy work or blocking I/O without starving other tasks.
+    /// Otherwise, it behaves like an async job function: it takes this job's
+    /// [`Monitor`] and returns any type that implements
+    /// <code>[Into]&lt;[JobReturnStatus]&gt;</code>, and panics are caught.
+    ///
+    /// Note that blocking jobs cannot be [cancelled](Monitor::cancel).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// use girlboss::tokio::Job;
+    ///
+    /// let job = Job::start_blocking(|mon| {
+    ///     let sum: u64 = (1..=1_000_000).sum();
+    ///     write!(mon, "The sum is {sum}");
+    /// });
+    /// job.wait().await.unwrap();
+    /// assert_eq!(job.status().message(), "The sum is 500000500000");
+    /// # }
+    /// ```
+    pub fn start_blocking<F, T>(func: F) -> Self
+    where
+        F: FnOnce(Monitor) -> T + Send + 'static,
+        T: Into<JobReturnStatus>,
+    {
+        Job::start_blocking_with_monitor(Monitor::starting(), func, None)
+    }
+
+    /// Starts a new blocking job that reports to an existing, not yet started
+    /// monitor, using `spawner` if there is one.
+    pub(crate) fn start_blocking_with_monitor<F, T>(
+        monitor: Monitor,
+        func: F,
+        spawner: Option<&R::Spawner>,
+    ) -> Self
+    where
+        F: FnOnce(Monitor) -> T + Send + 'static,
+        T: Into<JobReturnStatus>,
+    {
+        let handle = match spawner {
+            Some(spawner) => runtime::spawn_blocking_on::<R, _, _>(spawner, func, monitor.clone()),
+            None => runtime::spawn_blocking::<R, _, _>(func, monitor.clone()),
+        };
+        Job {
+            handle: Arc::new(handle),
+            monitor,
+        }
+    }
+
     /// Waits for this job to finish.
     ///
     /// If the job indicated that it failed, this returns
@@ -75,6 +247,102 @@ impl<R: Runtime> Job<R> {
             Err(Error::JobFailed)
         }
     }
+
+    /// Returns a future that resolves to the same result as
+    /// [`wait`](Self::wait) once this job finishes.
+    ///
+    /// Unlike `wait`, the returned future does not borrow the job, and it can
+    /// be cloned to wait for the job from several places at once. It also
+    /// implements [`FusedFuture`], so it can be used with
+    /// [`futures::select!`](https://docs.rs/futures/latest/futures/macro.select.html).
+    /// `Job` itself implements [`IntoFuture`] using this future, so a job can be
+    /// `.await`ed directly.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// use girlboss::tokio::Job;
+    ///
+    /// let job = Job::start(|_| async { Err::<(), _>("Out of coffee") });
+    /// let (a, b) = futures::join!(job.future(), job.future());
+    /// assert_eq!(a, b);
+    /// assert!(job.await.is_err());
+    /// # }
+    /// ```
+    pub fn future(&self) -> JobFuture {
+        let handle = self.handle.clone();
+        JobFuture::new(
+            self.monitor.finished(),
+            async move { handle.wait().await }.boxed(),
+        )
+    }
+
+    /// Waits for this job to finish, but gives up after `timeout`.
+    ///
+    /// If the job is not finished in time, this returns
+    /// <code>Err([Error::Timeout])</code>, and the job keeps running.
+    /// Otherwise, this behaves like [`wait`](Self::wait).
+    pub async fn wait_timeout(&self, timeout: Duration) -> Result<()> {
+        runtime::timeout::<R, _>(timeout, self.wait()).await?
+    }
+
+    /// Alias of
+    /// <code>self.monitor().[wait_blocking](Monitor::wait_blocking)()</code>.
+    pub fn wait_blocking(&self) -> Result<()> {
+        self.monitor.wait_blocking()
+    }
+
+    /// Alias of
+    /// <code>self.monitor().[wait_blocking_timeout](Monitor::wait_blocking_timeout)(timeout)</code>.
+    pub fn wait_blocking_timeout(&self, timeout: Duration) -> Result<()> {
+        self.monitor.wait_blocking_timeout(timeout)
+    }
+
+    /// Alias of <code>self.monitor().[cancel](Monitor::cancel)()</code>.
+    pub fn cancel(&self) {
+        self.monitor.cancel()
+    }
+
+    /// Alias of
+    /// <code>self.monitor().[on_finish](Monitor::on_finish)(callback)</code>.
+    pub fn on_finish(&self, callback: impl FnOnce(bool, JobStatus) + Send + 'static) {
+        self.monitor.on_finish(callback)
+    }
+
+    /// Starts a new job that begins once this job has finished, whether it
+    /// succeeded or not.
+    ///
+    /// This is useful for async continuations. The new job can check how
+    /// this job went through this job's [`monitor`](Self::monitor).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// use girlboss::tokio::Job;
+    ///
+    /// let job = Job::start(|_| async { "Downloaded" });
+    /// let previous = job.monitor().clone();
+    /// let next = job.then(|mon| async move {
+    ///     write!(mon, "Previous job said: {}", previous.status().message());
+    /// });
+    /// next.wait().await.unwrap();
+    /// assert_eq!(next.status().message(), "Previous job said: Downloaded");
+    /// # }
+    /// ```
+    pub fn then<F, Fut>(&self, func: F) -> Job<R>
+    where
+        F: FnOnce(Monitor) -> Fut,
+        Fut: Spawnable<R>,
+        <Fut as Future>::Output: Into<JobReturnStatus>,
+    {
+        let monitor = Monitor::starting();
+        monitor.set_predecessor(self.monitor.clone());
+        Job::start_with_monitor(monitor, func, None)
+    }
 }
 
 /// Methods to check the status of a job.
@@ -107,6 +375,86 @@ impl<R: Runtime> Job<R> {
     }
 }
 
+impl<R: Runtime> IntoFuture for Job<R> {
+    type Output = Result<()>;
+    type IntoFuture = JobFuture;
+
+    fn into_future(self) -> JobFuture {
+        self.future()
+    }
+}
+
+impl<R: Runtime> IntoFuture for &Job<R> {
+    type Output = Result<()>;
+    type IntoFuture = JobFuture;
+
+    fn into_future(self) -> JobFuture {
+        self.future()
+    }
+}
+
+/// A future that resolves once a job finishes, returned by [`Job::future`].
+///
+/// The output is the same as that of [`Job::wait`]. This future is
+/// runtime-agnostic, [`Send`], [`Sync`] and [`Unpin`], and it can be cloned
+/// cheaply.
+#[derive(Clone)]
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct JobFuture {
+    finished: Finished,
+    /// Completes once the job's task is done, even if it was dropped before
+    /// the job could finish, e.g. because its runtime shut down.
+    joined: Shared<BoxFuture<'static, ()>>,
+    is_terminated: bool,
+}
+
+impl JobFuture {
+    pub(super) fn new(finished: Finished, joined: BoxFuture<'static, ()>) -> Self {
+        JobFuture {
+            finished,
+            joined: joined.shared(),
+            is_terminated: false,
+        }
+    }
+}
+
+impl Future for JobFuture {
+    type Output = Result<()>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<()>> {
+        let succeeded = match Pin::new(&mut self.finished).poll(cx) {
+            Poll::Ready(succeeded) => succeeded,
+            // A task that is gone without finishing the job counts as failed,
+            // like it does for `wait`.
+            Poll::Pending => match Pin::new(&mut self.joined).poll(cx) {
+                Poll::Ready(()) => self.finished.monitor().succeeded(),
+                Poll::Pending => return Poll::Pending,
+            },
+        };
+        self.is_terminated = true;
+        if succeeded {
+            Poll::Ready(Ok(()))
+        } else {
+            Poll::Ready(Err(Error::JobFailed))
+        }
+    }
+}
+
+impl fmt::Debug for JobFuture {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("JobFuture")
+            .field("finished", &self.finished)
+            .field("is_terminated", &self.is_terminated)
+            .finish_non_exhaustive()
+    }
+}
+
+impl FusedFuture for JobFuture {
+    fn is_terminated(&self) -> bool {
+        self.is_terminated
+    }
+}
+
 impl<R: Runtime> AsRef<Monitor> for Job<R> {
     fn as_ref(&self) -> &Monitor {
         &self.monitor
@@ -0,0 +1,155 @@
+use std::fmt;
+use std::future::Future;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+use std::time::Duration;
+
+use futures::{future, Stream};
+
+use crate::runtime::{self, Runtime};
+use crate::Result;
+
+use super::{Job, JobFuture};
+
+/// A collection of jobs that can be waited on together.
+///
+/// A `JobSet` is also a [`Stream`] that yields its jobs in the order that they
+/// finish, removing each job from the set as it is yielded.
+///
+/// # Examples
+///
+/// ```
+/// # #[tokio::main]
+/// # async fn main() {
+/// use futures::StreamExt;
+/// use girlboss::tokio::{Job, JobSet};
+///
+/// let mut jobs: JobSet = (1..=3)
+///     .map(|i| Job::start(move |mon| async move { write!(mon, "Job {i}") }))
+///     .collect();
+/// jobs.wait_all().await.unwrap();
+///
+/// let mut finished = 0;
+/// while let Some(job) = jobs.next().await {
+///     assert!(job.succeeded());
+///     finished += 1;
+/// }
+/// assert_eq!(finished, 3);
+/// # }
+/// ```
+pub struct JobSet<R: Runtime> {
+    jobs: Vec<(Job<R>, JobFuture)>,
+}
+
+impl<R: Runtime> JobSet<R> {
+    /// Creates an empty `JobSet`.
+    pub fn new() -> Self {
+        JobSet { jobs: Vec::new() }
+    }
+
+    /// Adds a job to this set.
+    pub fn insert(&mut self, job: Job<R>) {
+        let future = job.future();
+        self.jobs.push((job, future));
+    }
+
+    /// Returns the number of jobs in this set.
+    pub fn len(&self) -> usize {
+        self.jobs.len()
+    }
+
+    /// Returns `true` if this set contains no jobs.
+    pub fn is_empty(&self) -> bool {
+        self.jobs.is_empty()
+    }
+
+    /// Iterates over the jobs in this set, in the order they were inserted,
+    /// except that yielding jobs from the stream may reorder the rest.
+    pub fn iter(&self) -> impl Iterator<Item = &Job<R>> + '_ {
+        self.jobs.iter().map(|(job, _)| job)
+    }
+
+    /// Waits for every job in this set to finish.
+    ///
+    /// If any of the jobs failed, this returns
+    /// <code>Err([Error::JobFailed](crate::Error::JobFailed))</code>.
+    /// Otherwise, it returns `Ok(())`. Either way, this only returns once all
+    /// jobs are finished.
+    pub async fn wait_all(&self) -> Result<()> {
+        let results = future::join_all(self.jobs.iter().map(|(_, future)| future.clone())).await;
+        results.into_iter().collect()
+    }
+
+    /// Waits for any job in this set to finish, and returns that job. If
+    /// multiple jobs are already finished, then any of them may be returned.
+    ///
+    /// Returns `None` if the set is empty. Unlike the [`Stream`]
+    /// implementation, this does not remove the job from the set.
+    pub async fn wait_any(&self) -> Option<&Job<R>> {
+        if self.is_empty() {
+            return None;
+        }
+        let futures = self.jobs.iter().map(|(_, future)| future.clone());
+        let (_, index, _) = future::select_all(futures).await;
+        Some(&self.jobs[index].0)
+    }
+
+    /// Like [`wait_all`](Self::wait_all), but gives up and returns
+    /// <code>Err([Error::Timeout](crate::Error::Timeout))</code> if the jobs
+    /// are not all finished after `timeout`.
+    pub async fn wait_all_timeout(&self, timeout: Duration) -> Result<()> {
+        runtime::timeout::<R, _>(timeout, self.wait_all()).await?
+    }
+}
+
+impl<R: Runtime> Stream for JobSet<R> {
+    type Item = Job<R>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Job<R>>> {
+        if self.jobs.is_empty() {
+            return Poll::Ready(None);
+        }
+        let index = self
+            .jobs
+            .iter_mut()
+            .position(|(_, future)| Pin::new(future).poll(cx).is_ready());
+        match index {
+            Some(index) => Poll::Ready(Some(self.jobs.swap_remove(index).0)),
+            None => Poll::Pending,
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len(), Some(self.len()))
+    }
+}
+
+impl<R: Runtime> Default for JobSet<R> {
+    fn default() -> Self {
+        JobSet::new()
+    }
+}
+
+impl<R: Runtime> FromIterator<Job<R>> for JobSet<R> {
+    fn from_iter<I: IntoIterator<Item = Job<R>>>(iter: I) -> Self {
+        let mut set = JobSet::new();
+        set.extend(iter);
+        set
+    }
+}
+
+impl<R: Runtime> Extend<Job<R>> for JobSet<R> {
+    fn extend<I: IntoIterator<Item = Job<R>>>(&mut self, iter: I) {
+        for job in iter {
+            self.insert(job);
+        }
+    }
+}
+
+impl<R: Runtime> fmt::Debug for JobSet<R> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list()
+            .entries(self.iter().map(Job::monitor))
+            .finish()
+    }
+}
@@ -5,13 +5,22 @@ use thiserror::Error as ThisError;
 #[non_exhaustive]
 pub enum Error {
     /// Returned by [`Girlboss::start`](crate::Girlboss::start) when the
-    /// specified job ID already exists and that job is not finished.
+    /// specified job ID already exists, that job is not finished, and the
+    /// [`ConflictPolicy`](crate::ConflictPolicy) is to reject the new job.
     #[error("A job with that ID already exists")]
     JobExists,
     /// Returned by [`Job::wait`](crate::common::Job::wait) when the job
     /// returned an error or panicked.
     #[error("Job failed")]
     JobFailed,
+    /// Returned by [`Girlboss::start_auto`](crate::Girlboss::start_auto) when
+    /// the job manager has no [`IdGenerator`](crate::id::IdGenerator).
+    #[error("No ID generator was configured")]
+    NoIdGenerator,
+    /// Returned by [`Job::wait_timeout`](crate::common::Job::wait_timeout)
+    /// and similar methods when the job did not finish in time.
+    #[error("Timed out waiting for the job")]
+    Timeout,
 }
 
 /// An alias of [`Result`](std::result::Result) with the default error type
@@ -0,0 +1,172 @@
+//! Job ID generators.
+//!
+//! A [`Girlboss`](crate::Girlboss) can be configured with an [`IdGenerator`]
+//! using [`set_id_generator`](crate::Girlboss::set_id_generator), after which
+//! [`start_auto`](crate::Girlboss::start_auto) can be used to start jobs
+//! without coming up with an ID first.
+//!
+//! Any `FnMut() -> K` closure can be used as a generator. This module also
+//! provides a couple of ready-made generators:
+//!
+//! * [`Counter`], which generates sequential `u64` IDs.
+//! * [`Ulid`], which generates time-sortable, ULID-like `String` IDs.
+
+use std::collections::hash_map::RandomState;
+use std::hash::{BuildHasher, Hasher};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// A source of job IDs of type `K`.
+///
+/// The generated IDs do not strictly have to be unique, since
+/// [`start_auto`](crate::Girlboss::start_auto) skips IDs that are already in
+/// use, but generators should try to avoid repeating themselves.
+pub trait IdGenerator<K>: Send + Sync {
+    /// Returns a new ID.
+    fn next_id(&mut self) -> K;
+}
+
+impl<K, F> IdGenerator<K> for F
+where
+    F: FnMut() -> K + Send + Sync,
+{
+    fn next_id(&mut self) -> K {
+        self()
+    }
+}
+
+/// Generates sequential `u64` IDs.
+///
+/// # Examples
+///
+/// ```
+/// use girlboss::id::{Counter, IdGenerator};
+///
+/// let mut ids = Counter::starting_at(1);
+/// assert_eq!(ids.next_id(), 1);
+/// assert_eq!(ids.next_id(), 2);
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct Counter {
+    next: u64,
+}
+
+impl Counter {
+    /// Creates a counter that generates IDs starting from zero.
+    pub fn new() -> Self {
+        Counter::default()
+    }
+
+    /// Creates a counter that generates IDs starting from `first`.
+    pub fn starting_at(first: u64) -> Self {
+        Counter { next: first }
+    }
+}
+
+impl IdGenerator<u64> for Counter {
+    fn next_id(&mut self) -> u64 {
+        let id = self.next;
+        self.next = self.next.wrapping_add(1);
+        id
+    }
+}
+
+/// Generates time-sortable `String` IDs in the style of
+/// [ULID](https://github.com/ulid/spec).
+///
+/// Each ID is 26 characters long, consisting of a 48-bit millisecond timestamp
+/// followed by 80 bits of randomness, encoded with Crockford's base32. IDs
+/// sort lexicographically in the order that they were generated, even within
+/// the same millisecond.
+///
+/// The randomness is not cryptographically secure, so these IDs should not be
+/// used as secrets.
+///
+/// # Examples
+///
+/// ```
+/// use girlboss::id::{IdGenerator, Ulid};
+///
+/// let mut ids = Ulid::new();
+/// let first = ids.next_id();
+/// let second = ids.next_id();
+/// assert_eq!(first.len(), 26);
+/// assert!(first < second);
+/// ```
+#[derive(Debug, Clone)]
+pub struct Ulid {
+    rng: u64,
+    last_timestamp: u64,
+    last_random: u128,
+}
+
+const RANDOM_BITS: u32 = 80;
+const CROCKFORD_BASE32: &[u8; 32] = b"0123456789ABCDEFGHJKMNPQRSTVWXYZ";
+
+impl Ulid {
+    /// Creates a new generator.
+    pub fn new() -> Self {
+        Ulid {
+            rng: RandomState::new().build_hasher().finish(),
+            last_timestamp: 0,
+            last_random: 0,
+        }
+    }
+
+    /// Creates a generator whose last ID had the given timestamp and random
+    /// part.
+    #[cfg(test)]
+    pub(crate) fn with_last(last_timestamp: u64, last_random: u128) -> Self {
+        Ulid {
+            last_timestamp,
+            last_random,
+            ..Ulid::new()
+        }
+    }
+
+    /// Returns the next pseudorandom number using SplitMix64.
+    fn next_random(&mut self) -> u64 {
+        self.rng = self.rng.wrapping_add(0x9e3779b97f4a7c15);
+        let mut z = self.rng;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d049bb133111eb);
+        z ^ (z >> 31)
+    }
+}
+
+impl Default for Ulid {
+    fn default() -> Self {
+        Ulid::new()
+    }
+}
+
+impl IdGenerator<String> for Ulid {
+    fn next_id(&mut self) -> String {
+        let timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |time| time.as_millis() as u64)
+            .max(self.last_timestamp);
+        let random_mask = (1 << RANDOM_BITS) - 1;
+
+        let (timestamp, random) = if timestamp != self.last_timestamp {
+            let high = u128::from(self.next_random()) << 64;
+            let low = u128::from(self.next_random());
+            (timestamp, (high | low) & random_mask)
+        } else if self.last_random < random_mask {
+            // Keep the IDs sorted within the same millisecond by incrementing
+            // the previous random part instead of generating a new one.
+            (timestamp, self.last_random + 1)
+        } else {
+            // The random part would overflow, so borrow the next millisecond
+            // instead of wrapping around to an ID that sorts before this one.
+            (timestamp + 1, 0)
+        };
+        self.last_timestamp = timestamp;
+        self.last_random = random;
+
+        let value = (u128::from(timestamp) << RANDOM_BITS) | random;
+        (0..26)
+            .rev()
+            .map(|i| CROCKFORD_BASE32[(value >> (i * 5)) as usize & 31] as char)
+            .collect()
+    }
+}
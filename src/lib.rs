@@ -4,30 +4,42 @@
 
 pub mod common;
 mod error;
+pub mod id;
 mod manager;
+#[cfg(feature = "metrics")]
+mod metric;
 mod monitor;
 mod return_status;
 pub mod runtime;
 mod status;
 mod tests;
+#[cfg(feature = "tracing")]
+pub mod trace;
 
 pub use error::{Error, Result};
-pub use manager::Girlboss;
-pub use monitor::Monitor;
+pub use manager::{
+    CleanupTask, ConflictPolicy, Event, EventStream, Girlboss, JobFilter, Page, SortBy,
+};
+#[cfg(feature = "metrics")]
+pub use manager::{Stats, StatsHandle};
+pub use monitor::{Finished, Monitor};
 pub use return_status::JobReturnStatus;
 pub use status::JobStatus;
 
-#[cfg(not(any(doc, feature = "tokio", feature = "actix-rt")))]
-compile_error!("you must specify at least one async runtime as a crate feature");
-
 macro_rules! make_runtime_module {
     ($module:ident = $name:literal , $runtime:ty) => {
+        make_runtime_module!($module = $name, $runtime, feature = $name);
+    };
+    ($module:ident = $name:literal , $runtime:ty , feature = $feature:literal) => {
         #[doc = concat!("Shortcuts for ", $name, "-specific types.")]
-        #[cfg(feature = $name)]
+        #[cfg(feature = $feature)]
         pub mod $module {
             #[doc = concat!($name, "-specific [`Job`](crate::common::Job) type.")]
             pub type Job = crate::common::Job<$runtime>;
 
+            #[doc = concat!($name, "-specific [`JobSet`](crate::common::JobSet) type.")]
+            pub type JobSet = crate::common::JobSet<$runtime>;
+
             #[doc = concat!($name, "-specific [`Girlboss`](crate::Girlboss) type.")]
             pub type Girlboss<K> = crate::Girlboss<K, Job>;
         }
@@ -35,4 +47,17 @@ macro_rules! make_runtime_module {
 }
 
 make_runtime_module!(tokio = "tokio", crate::runtime::Tokio);
+make_runtime_module!(tokio_local = "tokio-local", crate::runtime::TokioLocal);
 make_runtime_module!(actix_rt = "actix-rt", crate::runtime::ActixRt);
+make_runtime_module!(smol = "smol", crate::runtime::Smol);
+make_runtime_module!(
+    thread_pool = "ThreadPool",
+    crate::runtime::ThreadPool,
+    feature = "futures-executor"
+);
+make_runtime_module!(
+    local_pool = "LocalPool",
+    crate::runtime::LocalPool,
+    feature = "futures-executor"
+);
+make_runtime_module!(thread = "thread", crate::runtime::Thread);
@@ -1,13 +1,32 @@
+use std::any::{Any, TypeId};
 use std::borrow::Borrow;
 use std::collections::btree_map::Entry;
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, VecDeque};
 use std::future::Future;
 use std::time::{Duration, Instant};
 
-use crate::common::Job;
-use crate::runtime::{Runtime, Spawnable};
+use crate::common::{DynJob, Job};
+use crate::id::IdGenerator;
+use crate::runtime::{Adoptable, Runtime, Spawnable};
 use crate::{Error, JobReturnStatus, Monitor, Result};
 
+mod cleanup;
+mod events;
+mod policy;
+mod query;
+#[cfg(feature = "tracing")]
+mod spans;
+#[cfg(feature = "metrics")]
+mod stats;
+
+pub use cleanup::CleanupTask;
+use events::Observer;
+pub use events::{Event, EventStream};
+pub use policy::ConflictPolicy;
+pub use query::{JobFilter, Page, SortBy};
+#[cfg(feature = "metrics")]
+pub use stats::{Stats, StatsHandle};
+
 /// A job manager, which stores a mapping of job IDs to either jobs or monitors.
 ///
 /// This job manager can store either [`Job`]s or [`Monitor`]s. You can
@@ -15,23 +34,165 @@ use crate::{Error, JobReturnStatus, Monitor, Result};
 /// your async runtime (for example, [`girlboss::tokio`](crate::tokio)), which
 /// stores [`Job`]s of that runtime. However, if your runtime's `Job` is not
 /// `Send` or `Sync`, you can choose to store [`Monitor`]s instead, which are
-/// guaranteed to be `Send + Sync`.
+/// guaranteed to be `Send + Sync`. Monitors can still be waited on with
+/// [`Monitor::finished`].
+///
+/// To store jobs of several runtimes in one manager, store [`DynJob`]s, which
+/// can be waited on like the jobs they were created from.
 ///
 /// This job manager continues to store jobs/monitors even after they are
 /// finished, and this is by design. Finished jobs/monitors can be overwritten
-/// with [`start`](Self::start) or cleared with [`cleanup`](Self::cleanup).
+/// with [`start`](Self::start) or cleared with [`cleanup`](Self::cleanup). To
+/// clear them automatically, see [`spawn_cleanup`](Self::spawn_cleanup).
+///
+/// What happens when a job is started with the ID of a job that is still in
+/// progress is determined by the manager's [`ConflictPolicy`].
 ///
 /// The job ID type, `K`, must implement [`Ord`] because the implementation
 /// currently uses a [`BTreeMap`].
 pub struct Girlboss<K: Ord, V: AsRef<Monitor> + Clone> {
-    jobs: BTreeMap<K, V>,
+    jobs: BTreeMap<K, Slot<V>>,
+    id_generator: Option<Box<dyn IdGenerator<K>>>,
+    conflict_policy: ConflictPolicy,
+    history_limit: Option<usize>,
+    observers: Vec<Box<dyn Observer<K>>>,
+    /// The [`Runtime::Spawner`] of each runtime that has one set, keyed by
+    /// the runtime's type.
+    spawners: BTreeMap<TypeId, Box<dyn Any + Send + Sync>>,
+    /// Whether [`record_span_ids`](Self::record_span_ids) has been called.
+    #[cfg(feature = "tracing")]
+    records_span_ids: bool,
+    #[cfg(feature = "metrics")]
+    stats: std::sync::Arc<stats::Counters>,
+}
+
+/// The jobs stored under a single ID.
+struct Slot<V> {
+    /// The latest job.
+    current: V,
+    /// Previous jobs, most recent first.
+    history: VecDeque<V>,
 }
 
+impl<V> Slot<V> {
+    fn new(current: V) -> Self {
+        Slot {
+            current,
+            history: VecDeque::new(),
+        }
+    }
+
+    /// Replaces the latest job, moving the old one into the history if
+    /// `keep` is true. The history is then trimmed to `limit` entries.
+    ///
+    /// Returns the jobs that were discarded.
+    fn replace(&mut self, current: V, keep: bool, limit: Option<usize>) -> Vec<V> {
+        let old = std::mem::replace(&mut self.current, current);
+        let mut evicted = Vec::new();
+        if keep {
+            self.history.push_front(old);
+        } else {
+            evicted.push(old);
+        }
+        if let Some(limit) = limit {
+            evicted.extend(self.history.drain(limit.min(self.history.len())..));
+        }
+        evicted
+    }
+}
+
+/// The number of IDs that [`Girlboss::start_auto`] tries before giving up.
+const MAX_ID_ATTEMPTS: usize = 16;
+
 impl<K: Ord, V: AsRef<Monitor> + Clone> Girlboss<K, V> {
     /// Creates a new empty job manager.
     pub fn new() -> Self {
-        Girlboss {
+        #[allow(unused_mut)]
+        let mut manager = Girlboss {
             jobs: BTreeMap::new(),
+            id_generator: None,
+            conflict_policy: ConflictPolicy::default(),
+            history_limit: None,
+            observers: Vec::new(),
+            spawners: BTreeMap::new(),
+            #[cfg(feature = "tracing")]
+            records_span_ids: false,
+            #[cfg(feature = "metrics")]
+            stats: Default::default(),
+        };
+        #[cfg(feature = "metrics")]
+        {
+            let observer = stats::StatsObserver(manager.stats.clone());
+            manager.observers.push(Box::new(observer));
+        }
+        manager
+    }
+
+    /// Sets the [`IdGenerator`] used by [`start_auto`](Self::start_auto).
+    ///
+    /// See the [`id`](crate::id) module for the bundled generators.
+    pub fn set_id_generator(&mut self, generator: impl IdGenerator<K> + 'static) {
+        self.id_generator = Some(Box::new(generator));
+    }
+
+    /// Sets the [`ConflictPolicy`] used by [`start`](Self::start) and
+    /// [`start_auto`](Self::start_auto).
+    pub fn set_conflict_policy(&mut self, policy: ConflictPolicy) {
+        self.conflict_policy = policy;
+    }
+
+    /// Returns the [`ConflictPolicy`] used by [`start`](Self::start) and
+    /// [`start_auto`](Self::start_auto).
+    pub fn conflict_policy(&self) -> ConflictPolicy {
+        self.conflict_policy
+    }
+
+    /// Keeps up to `limit` previous jobs per ID, which can be retrieved with
+    /// [`history`](Self::history).
+    ///
+    /// By default, previous jobs are discarded when they are overwritten,
+    /// except when using the [`KeepHistory`](ConflictPolicy::KeepHistory)
+    /// conflict policy, in which case all of them are kept. After calling this
+    /// method, every overwritten job is kept, and only the `limit` most recent
+    /// previous jobs are kept for each ID regardless of the conflict policy.
+    ///
+    /// Previous jobs are also removed by [`cleanup`](Self::cleanup).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// use girlboss::tokio::Girlboss;
+    ///
+    /// let mut manager = Girlboss::<String>::new();
+    /// manager.set_history_limit(3);
+    ///
+    /// for night in 1..=4 {
+    ///     let job = manager
+    ///         .start("nightly", move |_| async move {
+    ///             Err::<(), _>(format!("Night {night} failed"))
+    ///         })
+    ///         .unwrap();
+    ///     job.wait().await.unwrap_err();
+    /// }
+    ///
+    /// let latest = manager.get("nightly").unwrap();
+    /// assert_eq!(latest.status().message(), "Night 4 failed");
+    /// let history: Vec<_> = manager
+    ///     .history("nightly")
+    ///     .iter()
+    ///     .map(|job| job.status().message().to_owned())
+    ///     .collect();
+    /// assert_eq!(history, ["Night 3 failed", "Night 2 failed", "Night 1 failed"]);
+    /// # }
+    /// ```
+    pub fn set_history_limit(&mut self, limit: usize) {
+        self.history_limit = Some(limit);
+        for (id, slot) in &mut self.jobs {
+            for job in slot.history.drain(limit.min(slot.history.len())..) {
+                notify_evicted(&self.observers, id, &job);
+            }
         }
     }
 
@@ -44,57 +205,376 @@ impl<K: Ord, V: AsRef<Monitor> + Clone> Girlboss<K, V> {
         Q: Ord + ?Sized,
         K: Borrow<Q>,
     {
-        self.jobs.get(id).cloned()
+        self.jobs.get(id).map(|slot| slot.current.clone())
+    }
+
+    /// Gets the previous jobs or monitors with the given ID, most recent
+    /// first.
+    ///
+    /// The latest job, as returned by [`get`](Self::get), is not included.
+    /// Previous jobs are only kept if a [history
+    /// limit](Self::set_history_limit) is set or when using the
+    /// [`KeepHistory`](ConflictPolicy::KeepHistory) conflict policy.
+    ///
+    /// Each job's outcome and duration can be checked through its [`Monitor`],
+    /// for example with [`outcome`](Monitor::outcome) and
+    /// [`elapsed`](Monitor::elapsed).
+    pub fn history<Q>(&self, id: &Q) -> Vec<V>
+    where
+        Q: Ord + ?Sized,
+        K: Borrow<Q>,
+    {
+        self.jobs
+            .get(id)
+            .map(|slot| slot.history.iter().cloned().collect())
+            .unwrap_or_default()
     }
 
     /// Removes all jobs that finished at least `max_age` time ago.
     ///
     /// If `max_age` is [`Duration::ZERO`], then all finished jobs are removed.
     ///
-    /// Jobs that are still in progress are never touched.
+    /// Jobs that are still in progress are never touched. If the latest job
+    /// with some ID is removed, then the finished jobs in the
+    /// [history](Self::history) of that ID are removed along with it, and the
+    /// most recent job that is still in progress, if any, becomes the latest
+    /// job.
     pub fn cleanup(&mut self, max_age: Duration) {
         let Some(max_time) = Instant::now().checked_sub(max_age) else {
             // The app hasn't been running for `max_age` time yet, so there's
             // nothing to delete.
             return;
         };
+        // If the job is finished and it's old enough, don't retain it.
+        let should_retain = move |job: &V| match job.as_ref().finished_at() {
+            Some(finished_at) => finished_at >= max_time,
+            None => true,
+        };
 
-        self.jobs
-            .retain(move |_, job| match job.as_ref().finished_at() {
-                // If the job is finished and it's old enough, don't retain it.
-                Some(finished_at) if finished_at < max_time => false,
-                _ => true,
+        let observers = &self.observers;
+        self.jobs.retain(move |id, slot| {
+            slot.history.retain(|job| {
+                let retain = should_retain(job);
+                if !retain {
+                    notify_evicted(observers, id, job);
+                }
+                retain
             });
+            if should_retain(&slot.current) {
+                return true;
+            }
+            notify_evicted(observers, id, &slot.current);
+
+            // With `KeepHistory`, a job in the history can still be running
+            // after the latest job finished. Such jobs are kept, and the most
+            // recent of them becomes the latest job.
+            slot.history.retain(|job| {
+                let running = !job.as_ref().is_finished();
+                if !running {
+                    notify_evicted(observers, id, job);
+                }
+                running
+            });
+            match slot.history.pop_front() {
+                Some(job) => {
+                    slot.current = job;
+                    true
+                }
+                None => false,
+            }
+        });
+    }
+
+    /// Generates an ID that is not used by any job, finished or not.
+    fn vacant_id(&mut self) -> Result<K> {
+        let generator = self.id_generator.as_mut().ok_or(Error::NoIdGenerator)?;
+        (0..MAX_ID_ATTEMPTS)
+            .map(|_| generator.next_id())
+            .find(|id| !self.jobs.contains_key(id))
+            .ok_or(Error::JobExists)
     }
 
-    fn try_insert(&mut self, id: K, f: impl FnOnce() -> V) -> Result<V> {
-        match self.jobs.entry(id) {
+    /// Returns the spawner set with `set_spawner` for the runtime `R`, if any.
+    fn spawner<R: Runtime>(&self) -> Option<R::Spawner> {
+        self.spawners
+            .get(&TypeId::of::<R>())?
+            .downcast_ref()
+            .cloned()
+    }
+
+    /// Sets the spawner returned by [`spawner`](Self::spawner) for the
+    /// runtime `R`.
+    fn insert_spawner<R: Runtime>(&mut self, spawner: R::Spawner) {
+        self.spawners.insert(TypeId::of::<R>(), Box::new(spawner));
+    }
+
+    /// Inserts the value created by `f` according to `policy`. `f` is given
+    /// the monitor that the new job must use.
+    fn try_insert(
+        &mut self,
+        id: K,
+        policy: ConflictPolicy,
+        f: impl FnOnce(Monitor) -> V,
+    ) -> Result<V> {
+        self.observers.retain(|observer| !observer.is_closed());
+
+        let mut occupied = match self.jobs.entry(id) {
             Entry::Vacant(vacant) => {
-                let value = f();
-                vacant.insert(value.clone());
-                Ok(value)
+                let value = f(new_monitor(&self.observers, vacant.key()));
+                vacant.insert(Slot::new(value.clone()));
+                return Ok(value);
             }
-            Entry::Occupied(mut occupied) => {
-                if occupied.get().as_ref().is_finished() {
-                    let value = f();
-                    occupied.insert(value.clone());
-                    Ok(value)
-                } else {
-                    Err(Error::JobExists)
+            Entry::Occupied(occupied) => occupied,
+        };
+
+        let old = occupied.get().current.as_ref().clone();
+        let predecessor = match policy {
+            _ if old.is_finished() => None,
+            ConflictPolicy::Reject => return Err(Error::JobExists),
+            ConflictPolicy::Replace => {
+                // The old job may itself be queued behind other jobs that are
+                // still running, so cancel all of them.
+                let mut queued = Some(old.clone());
+                while let Some(job) = queued {
+                    job.cancel();
+                    queued = job.predecessor();
                 }
+                Some(old)
             }
+            ConflictPolicy::Queue => Some(old),
+            ConflictPolicy::KeepHistory => None,
+        };
+
+        let monitor = new_monitor(&self.observers, occupied.key());
+        if let Some(predecessor) = predecessor {
+            monitor.set_predecessor(predecessor);
+        }
+        let value = f(monitor);
+        let keep = policy == ConflictPolicy::KeepHistory || self.history_limit.is_some();
+        let evicted = occupied
+            .get_mut()
+            .replace(value.clone(), keep, self.history_limit);
+        for job in &evicted {
+            notify_evicted(&self.observers, occupied.key(), job);
         }
+        Ok(value)
+    }
+
+    /// Returns the existing value if it is still running or it finished less
+    /// than `max_age` ago; otherwise, inserts the value created by `f`. `f` is
+    /// given the monitor that the new job must use.
+    fn get_or_insert(&mut self, id: K, max_age: Duration, f: impl FnOnce(Monitor) -> V) -> V {
+        self.observers.retain(|observer| !observer.is_closed());
+
+        let mut occupied = match self.jobs.entry(id) {
+            Entry::Vacant(vacant) => {
+                let value = f(new_monitor(&self.observers, vacant.key()));
+                return vacant.insert(Slot::new(value)).current.clone();
+            }
+            Entry::Occupied(occupied) => occupied,
+        };
+
+        match occupied.get().current.as_ref().finished_at() {
+            Some(finished_at) if finished_at.elapsed() >= max_age => {}
+            _ => return occupied.get().current.clone(),
+        }
+
+        let value = f(new_monitor(&self.observers, occupied.key()));
+        let keep = self.history_limit.is_some();
+        let evicted = occupied
+            .get_mut()
+            .replace(value.clone(), keep, self.history_limit);
+        for job in &evicted {
+            notify_evicted(&self.observers, occupied.key(), job);
+        }
+        value
+    }
+
+    /// Inserts the job created by `start` according to `policy`, storing the
+    /// value that `into` converts it to, and returns the job.
+    fn insert_job<R: Runtime>(
+        &mut self,
+        id: K,
+        policy: ConflictPolicy,
+        start: impl FnOnce(Monitor) -> Job<R>,
+        into: impl FnOnce(&Job<R>) -> V,
+    ) -> Result<Job<R>> {
+        let mut the_job = None;
+        self.try_insert(id, policy, |monitor| {
+            let job = start(monitor);
+            let value = into(&job);
+            the_job = Some(job);
+            value
+        })?;
+        Ok(the_job.unwrap())
+    }
+
+    /// Starts a job of the runtime `R`, which is stored as the value that
+    /// `into` converts it to.
+    fn start_job<R, F, Fut>(
+        &mut self,
+        id: K,
+        policy: ConflictPolicy,
+        func: F,
+        into: impl FnOnce(&Job<R>) -> V,
+    ) -> Result<Job<R>>
+    where
+        R: Runtime,
+        F: FnOnce(Monitor) -> Fut,
+        Fut: Spawnable<R>,
+        <Fut as Future>::Output: Into<JobReturnStatus>,
+    {
+        let spawner = self.spawner::<R>();
+        self.insert_job(
+            id,
+            policy,
+            |monitor| Job::start_with_monitor(monitor, func, spawner.as_ref()),
+            into,
+        )
+    }
+
+    /// Like [`start_job`](Self::start_job), but starts a blocking job.
+    fn start_blocking_job<R, F, T>(
+        &mut self,
+        id: K,
+        func: F,
+        into: impl FnOnce(&Job<R>) -> V,
+    ) -> Result<Job<R>>
+    where
+        R: Runtime,
+        F: FnOnce(Monitor) -> T + Send + 'static,
+        T: Into<JobReturnStatus>,
+    {
+        let spawner = self.spawner::<R>();
+        self.insert_job(
+            id,
+            self.conflict_policy,
+            |monitor| Job::start_blocking_with_monitor(monitor, func, spawner.as_ref()),
+            into,
+        )
+    }
+
+    /// Like [`start_job`](Self::start_job), but adopts the task returned by
+    /// `func`.
+    fn adopt_job<R, F, H>(
+        &mut self,
+        id: K,
+        func: F,
+        into: impl FnOnce(&Job<R>) -> V,
+    ) -> Result<Job<R>>
+    where
+        R: Runtime,
+        F: FnOnce(Monitor) -> H,
+        H: Adoptable<R>,
+    {
+        self.insert_job(
+            id,
+            self.conflict_policy,
+            |monitor| {
+                let handle = func(monitor.clone());
+                Job::adopt_with_monitor(monitor, handle)
+            },
+            into,
+        )
+    }
+
+    /// Like [`start_job`](Self::start_job), but uses an ID from the
+    /// [`IdGenerator`].
+    fn start_auto_job<R, F, Fut>(
+        &mut self,
+        func: F,
+        into: impl FnOnce(&Job<R>) -> V,
+    ) -> Result<(K, Job<R>)>
+    where
+        K: Clone,
+        R: Runtime,
+        F: FnOnce(Monitor) -> Fut,
+        Fut: Spawnable<R>,
+        <Fut as Future>::Output: Into<JobReturnStatus>,
+    {
+        let id = self.vacant_id()?;
+        let job = self.start_job(id.clone(), self.conflict_policy, func, into)?;
+        Ok((id, job))
+    }
+
+    /// Returns the existing value if it is still running or it finished less
+    /// than `max_age` ago; otherwise, starts a job of the runtime `R` and
+    /// stores it as the value that `into` converts it to.
+    fn start_or_get_job<R, F, Fut>(
+        &mut self,
+        id: K,
+        max_age: Duration,
+        func: F,
+        into: impl FnOnce(&Job<R>) -> V,
+    ) -> V
+    where
+        R: Runtime,
+        F: FnOnce(Monitor) -> Fut,
+        Fut: Spawnable<R>,
+        <Fut as Future>::Output: Into<JobReturnStatus>,
+    {
+        let spawner = self.spawner::<R>();
+        self.get_or_insert(id, max_age, |monitor| {
+            into(&Job::start_with_monitor(monitor, func, spawner.as_ref()))
+        })
+    }
+}
+
+/// Creates the monitor for a new job with the given ID.
+fn new_monitor<K>(observers: &[Box<dyn Observer<K>>], id: &K) -> Monitor {
+    let monitor = Monitor::starting();
+    for observer in observers {
+        observer.started(id, &monitor);
+    }
+    monitor
+}
+
+fn notify_evicted<K, V: AsRef<Monitor>>(observers: &[Box<dyn Observer<K>>], id: &K, job: &V) {
+    for observer in observers {
+        observer.evicted(id, job.as_ref());
     }
 }
 
 impl<K: Ord, R: Runtime> Girlboss<K, Job<R>> {
+    /// Starts this manager's jobs on `spawner` rather than the current
+    /// runtime. This includes blocking jobs.
+    ///
+    /// Only some runtimes support this; see [`Runtime::Spawner`]. For example,
+    /// with Tokio, the spawner is a runtime [`Handle`], which can be used to run
+    /// background jobs on a dedicated runtime. With actix-rt, it's an
+    /// `ArbiterHandle`, which is only used for blocking jobs, since async jobs
+    /// may not be `Send`; see the documentation of the `ActixRt` runtime.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// use girlboss::tokio::Girlboss;
+    ///
+    /// let background = tokio::runtime::Runtime::new().unwrap();
+    /// let mut manager = Girlboss::<String>::new();
+    /// manager.set_spawner(background.handle().clone());
+    ///
+    /// let job = manager.start("import", |_| async {}).unwrap();
+    /// job.wait().await.unwrap();
+    /// # background.shutdown_background();
+    /// # }
+    /// ```
+    ///
+    /// [`Handle`]: https://docs.rs/tokio/1/tokio/runtime/struct.Handle.html
+    pub fn set_spawner(&mut self, spawner: R::Spawner) {
+        self.insert_spawner::<R>(spawner);
+    }
+
     /// Starts and returns a new job with the provided ID.
     ///
     /// If there is already a job with the same ID, then:
     ///
     /// * If the old job is **finished**, then the old job will be **overwritten**
     ///   with the new job.
-    /// * If the old job is **not** finished, then the new job will **not** be
+    /// * If the old job is **not** finished, then what happens depends on the
+    ///   manager's [`ConflictPolicy`]. By default, the new job will **not** be
     ///   started and this method will return
     ///   <code>Err([Error::JobExists])</code>.
     ///
@@ -105,11 +585,206 @@ impl<K: Ord, R: Runtime> Girlboss<K, Job<R>> {
         Fut: Spawnable<R>,
         <Fut as Future>::Output: Into<JobReturnStatus>,
     {
-        self.try_insert(id.into(), || Job::start(func))
+        self.start_with_policy(id, self.conflict_policy, func)
+    }
+
+    /// Like [`start`](Self::start), but uses the given [`ConflictPolicy`]
+    /// instead of the manager's policy.
+    ///
+    /// # Examples
+    ///
+    /// Restarting a job that is in progress:
+    ///
+    /// ```
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// use std::future::pending;
+    ///
+    /// use girlboss::ConflictPolicy;
+    /// use girlboss::tokio::Girlboss;
+    ///
+    /// let mut manager = Girlboss::<String>::new();
+    /// let old_job = manager.start("import", |_| pending::<()>()).unwrap();
+    ///
+    /// let new_job = manager
+    ///     .start_with_policy("import", ConflictPolicy::Replace, |_| async {})
+    ///     .unwrap();
+    /// new_job.wait().await.unwrap();
+    ///
+    /// assert_eq!(old_job.status().message(), "The job was cancelled");
+    /// # }
+    /// ```
+    pub fn start_with_policy<F, Fut>(
+        &mut self,
+        id: impl Into<K>,
+        policy: ConflictPolicy,
+        func: F,
+    ) -> Result<Job<R>>
+    where
+        F: FnOnce(Monitor) -> Fut,
+        Fut: Spawnable<R>,
+        <Fut as Future>::Output: Into<JobReturnStatus>,
+    {
+        self.start_job(id.into(), policy, func, Job::clone)
+    }
+
+    /// Like [`start`](Self::start), but starts a blocking job. See
+    /// [`Job::start_blocking`] for information about the job function.
+    pub fn start_blocking<F, T>(&mut self, id: impl Into<K>, func: F) -> Result<Job<R>>
+    where
+        F: FnOnce(Monitor) -> T + Send + 'static,
+        T: Into<JobReturnStatus>,
+    {
+        self.start_blocking_job(id.into(), func, Job::clone)
+    }
+
+    /// Like [`start`](Self::start), but adopts a task that was spawned
+    /// outside of this crate. `func` is given the job's monitor, and it
+    /// returns the task's handle. See [`Job::adopt`] for more information.
+    ///
+    /// The task is already running by the time it's adopted, so if the
+    /// [`ConflictPolicy`] makes the job wait for a previous job, then only the
+    /// job's completion is delayed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// use girlboss::tokio::Girlboss;
+    ///
+    /// let mut manager = Girlboss::<String>::new();
+    /// let job = manager
+    ///     .adopt("sync", |mon| {
+    ///         tokio::spawn(async move {
+    ///             write!(mon, "Synced");
+    ///         })
+    ///     })
+    ///     .unwrap();
+    /// job.wait().await.unwrap();
+    /// assert_eq!(job.status().message(), "Synced");
+    /// # }
+    /// ```
+    pub fn adopt<F, H>(&mut self, id: impl Into<K>, func: F) -> Result<Job<R>>
+    where
+        F: FnOnce(Monitor) -> H,
+        H: Adoptable<R>,
+    {
+        self.adopt_job(id.into(), func, Job::clone)
+    }
+
+    /// Starts a new job with an ID from this manager's
+    /// [`IdGenerator`], returning both the ID and the job.
+    ///
+    /// IDs that are already in use, including by finished jobs, are skipped.
+    /// If no ID generator was [set](Self::set_id_generator), this returns
+    /// <code>Err([Error::NoIdGenerator])</code>. If the generator repeatedly
+    /// produces IDs that are in use, this gives up and returns
+    /// <code>Err([Error::JobExists])</code>.
+    ///
+    /// See [`Job::start`] for information about the job function.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// use girlboss::id::Counter;
+    /// use girlboss::tokio::Girlboss;
+    ///
+    /// let mut manager = Girlboss::<u64>::new();
+    /// manager.set_id_generator(Counter::new());
+    ///
+    /// let (id, job) = manager.start_auto(|_| async {}).unwrap();
+    /// assert_eq!(id, 0);
+    /// assert_eq!(manager.get(&id), Some(job));
+    /// # }
+    /// ```
+    pub fn start_auto<F, Fut>(&mut self, func: F) -> Result<(K, Job<R>)>
+    where
+        K: Clone,
+        F: FnOnce(Monitor) -> Fut,
+        Fut: Spawnable<R>,
+        <Fut as Future>::Output: Into<JobReturnStatus>,
+    {
+        self.start_auto_job(func, Job::clone)
+    }
+
+    /// Returns the job with the provided ID if it is still running, or starts
+    /// and returns a new job otherwise.
+    ///
+    /// Unlike calling [`get`](Self::get) and then [`start`](Self::start), this
+    /// happens in one step, so concurrent callers sharing this manager through
+    /// a lock can never end up starting the same job twice.
+    ///
+    /// A finished job with the same ID is overwritten, as with
+    /// [`start`](Self::start). To reuse recently finished jobs too, see
+    /// [`start_or_get_within`](Self::start_or_get_within).
+    ///
+    /// See [`Job::start`] for information about the job function.
+    pub fn start_or_get<F, Fut>(&mut self, id: impl Into<K>, func: F) -> Job<R>
+    where
+        F: FnOnce(Monitor) -> Fut,
+        Fut: Spawnable<R>,
+        <Fut as Future>::Output: Into<JobReturnStatus>,
+    {
+        self.start_or_get_within(id, Duration::ZERO, func)
+    }
+
+    /// Returns the job with the provided ID if it is still running or it
+    /// finished less than `max_age` ago, or starts and returns a new job
+    /// otherwise.
+    ///
+    /// This allows identical requests that arrive close together to share the
+    /// result of a single job.
+    ///
+    /// See [`Job::start`] for information about the job function.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// use std::time::Duration;
+    ///
+    /// use girlboss::tokio::Girlboss;
+    ///
+    /// let mut manager = Girlboss::<String>::new();
+    /// let minute = Duration::from_secs(60);
+    ///
+    /// let job1 = manager.start_or_get_within("report", minute, |_| async {});
+    /// job1.wait().await.unwrap();
+    ///
+    /// // The job finished less than a minute ago, so it's reused.
+    /// let job2 = manager.start_or_get_within("report", minute, |_| async {});
+    /// assert_eq!(job1, job2);
+    /// # }
+    /// ```
+    pub fn start_or_get_within<F, Fut>(
+        &mut self,
+        id: impl Into<K>,
+        max_age: Duration,
+        func: F,
+    ) -> Job<R>
+    where
+        F: FnOnce(Monitor) -> Fut,
+        Fut: Spawnable<R>,
+        <Fut as Future>::Output: Into<JobReturnStatus>,
+    {
+        self.start_or_get_job(id.into(), max_age, func, Job::clone)
     }
 }
 
 impl<K: Ord> Girlboss<K, Monitor> {
+    /// Additional implementation for a [`Monitor`]-storing job manager. See
+    /// [`Girlboss<K, Job<R>>::set_spawner`] for information.
+    ///
+    /// Only jobs of the runtime `R` use the spawner; jobs of other runtimes
+    /// use their own spawner, if one was set, or are spawned as usual.
+    pub fn set_spawner<R: Runtime>(&mut self, spawner: R::Spawner) {
+        self.insert_spawner::<R>(spawner);
+    }
+
     /// Additional implementation for a [`Monitor`]-storing job manager. See
     /// [`Girlboss<K, Job<R>>::start`] for information.
     pub fn start<R: Runtime, F, Fut>(&mut self, id: impl Into<K>, func: F) -> Result<Job<R>>
@@ -118,14 +793,191 @@ impl<K: Ord> Girlboss<K, Monitor> {
         Fut: Spawnable<R>,
         <Fut as Future>::Output: Into<JobReturnStatus>,
     {
-        let mut the_job = None;
-        self.try_insert(id.into(), || {
-            let job = Job::start(func);
-            let monitor = job.monitor().clone();
-            the_job = Some(job);
-            monitor
-        })?;
-        Ok(the_job.unwrap())
+        self.start_with_policy(id, self.conflict_policy, func)
+    }
+
+    /// Additional implementation for a [`Monitor`]-storing job manager. See
+    /// [`Girlboss<K, Job<R>>::start_with_policy`] for information.
+    pub fn start_with_policy<R: Runtime, F, Fut>(
+        &mut self,
+        id: impl Into<K>,
+        policy: ConflictPolicy,
+        func: F,
+    ) -> Result<Job<R>>
+    where
+        F: FnOnce(Monitor) -> Fut,
+        Fut: Spawnable<R>,
+        <Fut as Future>::Output: Into<JobReturnStatus>,
+    {
+        self.start_job(id.into(), policy, func, |job| job.monitor().clone())
+    }
+
+    /// Additional implementation for a [`Monitor`]-storing job manager. See
+    /// [`Girlboss<K, Job<R>>::start_blocking`] for information.
+    pub fn start_blocking<R: Runtime, F, T>(&mut self, id: impl Into<K>, func: F) -> Result<Job<R>>
+    where
+        F: FnOnce(Monitor) -> T + Send + 'static,
+        T: Into<JobReturnStatus>,
+    {
+        self.start_blocking_job(id.into(), func, |job| job.monitor().clone())
+    }
+
+    /// Additional implementation for a [`Monitor`]-storing job manager. See
+    /// [`Girlboss<K, Job<R>>::adopt`] for information.
+    pub fn adopt<R: Runtime, F, H>(&mut self, id: impl Into<K>, func: F) -> Result<Job<R>>
+    where
+        F: FnOnce(Monitor) -> H,
+        H: Adoptable<R>,
+    {
+        self.adopt_job(id.into(), func, |job| job.monitor().clone())
+    }
+
+    /// Additional implementation for a [`Monitor`]-storing job manager. See
+    /// [`Girlboss<K, Job<R>>::start_auto`] for information.
+    pub fn start_auto<R: Runtime, F, Fut>(&mut self, func: F) -> Result<(K, Job<R>)>
+    where
+        K: Clone,
+        F: FnOnce(Monitor) -> Fut,
+        Fut: Spawnable<R>,
+        <Fut as Future>::Output: Into<JobReturnStatus>,
+    {
+        self.start_auto_job(func, |job| job.monitor().clone())
+    }
+
+    /// Additional implementation for a [`Monitor`]-storing job manager. See
+    /// [`Girlboss<K, Job<R>>::start_or_get`] for information.
+    ///
+    /// Since only the monitor of an existing job is stored, this returns the
+    /// [`Monitor`] rather than the [`Job`].
+    pub fn start_or_get<R: Runtime, F, Fut>(&mut self, id: impl Into<K>, func: F) -> Monitor
+    where
+        F: FnOnce(Monitor) -> Fut,
+        Fut: Spawnable<R>,
+        <Fut as Future>::Output: Into<JobReturnStatus>,
+    {
+        self.start_or_get_within(id, Duration::ZERO, func)
+    }
+
+    /// Additional implementation for a [`Monitor`]-storing job manager. See
+    /// [`Girlboss<K, Job<R>>::start_or_get_within`] for information.
+    ///
+    /// Since only the monitor of an existing job is stored, this returns the
+    /// [`Monitor`] rather than the [`Job`].
+    pub fn start_or_get_within<R: Runtime, F, Fut>(
+        &mut self,
+        id: impl Into<K>,
+        max_age: Duration,
+        func: F,
+    ) -> Monitor
+    where
+        F: FnOnce(Monitor) -> Fut,
+        Fut: Spawnable<R>,
+        <Fut as Future>::Output: Into<JobReturnStatus>,
+    {
+        self.start_or_get_job(id.into(), max_age, func, |job| job.monitor().clone())
+    }
+}
+
+impl<K: Ord> Girlboss<K, DynJob> {
+    /// Additional implementation for a [`DynJob`]-storing job manager. See
+    /// [`Girlboss<K, Job<R>>::set_spawner`] for information.
+    ///
+    /// Only jobs of the runtime `R` use the spawner; jobs of other runtimes
+    /// use their own spawner, if one was set, or are spawned as usual.
+    pub fn set_spawner<R: Runtime>(&mut self, spawner: R::Spawner) {
+        self.insert_spawner::<R>(spawner);
+    }
+
+    /// Additional implementation for a [`DynJob`]-storing job manager. See
+    /// [`Girlboss<K, Job<R>>::start`] for information.
+    pub fn start<R: Runtime, F, Fut>(&mut self, id: impl Into<K>, func: F) -> Result<Job<R>>
+    where
+        F: FnOnce(Monitor) -> Fut,
+        Fut: Spawnable<R>,
+        <Fut as Future>::Output: Into<JobReturnStatus>,
+    {
+        self.start_with_policy(id, self.conflict_policy, func)
+    }
+
+    /// Additional implementation for a [`DynJob`]-storing job manager. See
+    /// [`Girlboss<K, Job<R>>::start_with_policy`] for information.
+    pub fn start_with_policy<R: Runtime, F, Fut>(
+        &mut self,
+        id: impl Into<K>,
+        policy: ConflictPolicy,
+        func: F,
+    ) -> Result<Job<R>>
+    where
+        F: FnOnce(Monitor) -> Fut,
+        Fut: Spawnable<R>,
+        <Fut as Future>::Output: Into<JobReturnStatus>,
+    {
+        self.start_job(id.into(), policy, func, |job| job.clone().into())
+    }
+
+    /// Additional implementation for a [`DynJob`]-storing job manager. See
+    /// [`Girlboss<K, Job<R>>::start_blocking`] for information.
+    pub fn start_blocking<R: Runtime, F, T>(&mut self, id: impl Into<K>, func: F) -> Result<Job<R>>
+    where
+        F: FnOnce(Monitor) -> T + Send + 'static,
+        T: Into<JobReturnStatus>,
+    {
+        self.start_blocking_job(id.into(), func, |job| job.clone().into())
+    }
+
+    /// Additional implementation for a [`DynJob`]-storing job manager. See
+    /// [`Girlboss<K, Job<R>>::adopt`] for information.
+    pub fn adopt<R: Runtime, F, H>(&mut self, id: impl Into<K>, func: F) -> Result<Job<R>>
+    where
+        F: FnOnce(Monitor) -> H,
+        H: Adoptable<R>,
+    {
+        self.adopt_job(id.into(), func, |job| job.clone().into())
+    }
+
+    /// Additional implementation for a [`DynJob`]-storing job manager. See
+    /// [`Girlboss<K, Job<R>>::start_auto`] for information.
+    pub fn start_auto<R: Runtime, F, Fut>(&mut self, func: F) -> Result<(K, Job<R>)>
+    where
+        K: Clone,
+        F: FnOnce(Monitor) -> Fut,
+        Fut: Spawnable<R>,
+        <Fut as Future>::Output: Into<JobReturnStatus>,
+    {
+        self.start_auto_job(func, |job| job.clone().into())
+    }
+
+    /// Additional implementation for a [`DynJob`]-storing job manager. See
+    /// [`Girlboss<K, Job<R>>::start_or_get`] for information.
+    ///
+    /// Since the existing job may be of another runtime, this returns a
+    /// [`DynJob`] rather than a [`Job`].
+    pub fn start_or_get<R: Runtime, F, Fut>(&mut self, id: impl Into<K>, func: F) -> DynJob
+    where
+        F: FnOnce(Monitor) -> Fut,
+        Fut: Spawnable<R>,
+        <Fut as Future>::Output: Into<JobReturnStatus>,
+    {
+        self.start_or_get_within(id, Duration::ZERO, func)
+    }
+
+    /// Additional implementation for a [`DynJob`]-storing job manager. See
+    /// [`Girlboss<K, Job<R>>::start_or_get_within`] for information.
+    ///
+    /// Since the existing job may be of another runtime, this returns a
+    /// [`DynJob`] rather than a [`Job`].
+    pub fn start_or_get_within<R: Runtime, F, Fut>(
+        &mut self,
+        id: impl Into<K>,
+        max_age: Duration,
+        func: F,
+    ) -> DynJob
+    where
+        F: FnOnce(Monitor) -> Fut,
+        Fut: Spawnable<R>,
+        <Fut as Future>::Output: Into<JobReturnStatus>,
+    {
+        self.start_or_get_job(id.into(), max_age, func, |job| job.clone().into())
     }
 }
 
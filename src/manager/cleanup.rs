@@ -0,0 +1,120 @@
+use std::fmt;
+use std::pin::pin;
+use std::sync::{Arc, Mutex, PoisonError};
+use std::time::Duration;
+
+use futures::channel::oneshot;
+use futures::future::{self, BoxFuture, Either};
+
+use crate::common::Job;
+use crate::runtime::{Runtime, Spawnable};
+use crate::{Girlboss, Monitor};
+
+/// A background task that periodically removes old finished jobs from a
+/// [`Girlboss`].
+///
+/// This is returned by [`Girlboss::spawn_cleanup`]. The task keeps running
+/// until [`stop`](Self::stop) is called, the `CleanupTask` is dropped, or the
+/// job manager itself is dropped, whichever comes first.
+#[must_use = "the cleanup task stops when this handle is dropped"]
+pub struct CleanupTask<R: Runtime> {
+    job: Job<R>,
+    shutdown: oneshot::Sender<()>,
+}
+
+impl<R: Runtime> CleanupTask<R> {
+    /// Returns the [`Job`] that performs the cleanup.
+    pub fn job(&self) -> &Job<R> {
+        &self.job
+    }
+
+    /// Stops the cleanup task and waits for it to finish.
+    ///
+    /// A cleanup that is already in progress is allowed to complete, but no
+    /// further cleanups will happen afterwards.
+    pub async fn stop(self) {
+        let _ = self.shutdown.send(());
+        let _ = self.job.wait().await;
+    }
+}
+
+impl<R: Runtime> fmt::Debug for CleanupTask<R> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("CleanupTask").field(&self.job).finish()
+    }
+}
+
+impl<K, V> Girlboss<K, V>
+where
+    K: Ord + Send + 'static,
+    V: AsRef<Monitor> + Clone + Send + 'static,
+{
+    /// Spawns a background task that calls [`cleanup`](Self::cleanup) with
+    /// the given `max_age` every `interval`, so that finished jobs expire on
+    /// their own.
+    ///
+    /// The task only holds a weak reference to the manager, so it does not
+    /// keep the manager alive, and it stops by itself once the manager is
+    /// dropped. It can also be stopped with [`CleanupTask::stop`] or by
+    /// dropping the returned [`CleanupTask`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// use std::sync::{Arc, Mutex};
+    /// use std::time::Duration;
+    ///
+    /// use girlboss::runtime::Tokio;
+    /// use girlboss::tokio::Girlboss;
+    ///
+    /// let manager: Arc<Mutex<Girlboss<String>>> = Arc::default();
+    /// let cleanup = Girlboss::spawn_cleanup::<Tokio>(
+    ///     &manager,
+    ///     Duration::from_secs(60),
+    ///     Duration::from_secs(60 * 60),
+    /// );
+    ///
+    /// // ... use the manager ...
+    ///
+    /// cleanup.stop().await;
+    /// # }
+    /// ```
+    pub fn spawn_cleanup<R: Runtime>(
+        manager: &Arc<Mutex<Self>>,
+        interval: Duration,
+        max_age: Duration,
+    ) -> CleanupTask<R>
+    where
+        BoxFuture<'static, ()>: Spawnable<R>,
+    {
+        let manager = Arc::downgrade(manager);
+        let (shutdown, mut shutdown_rx) = oneshot::channel::<()>();
+
+        let job = Job::start(move |mon: Monitor| -> BoxFuture<'static, ()> {
+            Box::pin(async move {
+                write!(
+                    mon,
+                    "Cleaning up jobs older than {max_age:?} every {interval:?}"
+                );
+                loop {
+                    let sleep = pin!(R::sleep(interval));
+                    if let Either::Right(_) = future::select(sleep, &mut shutdown_rx).await {
+                        // Either `stop` was called or the handle was dropped.
+                        break;
+                    }
+                    let Some(manager) = manager.upgrade() else {
+                        break;
+                    };
+                    // A panic elsewhere doesn't leave the map in an
+                    // inconsistent state, so it's fine to ignore poisoning.
+                    let mut manager = manager.lock().unwrap_or_else(PoisonError::into_inner);
+                    manager.cleanup(max_age);
+                }
+            })
+        });
+
+        CleanupTask { job, shutdown }
+    }
+}
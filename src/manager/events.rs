@@ -0,0 +1,234 @@
+use std::pin::Pin;
+use std::sync::{Arc, Weak};
+use std::task::{Context, Poll};
+
+use futures::channel::mpsc;
+use futures::Stream;
+
+use crate::monitor::MonitorEvent;
+use crate::{Girlboss, JobStatus, Monitor};
+
+/// A lifecycle event of a job in a [`Girlboss`].
+///
+/// See [`Girlboss::subscribe`] and [`Girlboss::on_event`].
+#[derive(Debug, Clone)]
+#[non_exhaustive]
+pub enum Event<K> {
+    /// A job was started.
+    Started {
+        /// The ID of the job.
+        id: K,
+        /// The job's monitor.
+        monitor: Monitor,
+    },
+    /// A job reported a new status.
+    Reported {
+        /// The ID of the job.
+        id: K,
+        /// The status that was reported.
+        status: JobStatus,
+    },
+    /// A job finished.
+    Finished {
+        /// The ID of the job.
+        id: K,
+        /// Whether the job succeeded. See [`Monitor::outcome`].
+        outcome: bool,
+        /// The job's monitor.
+        monitor: Monitor,
+    },
+    /// A job was removed from the job manager, either because it was
+    /// overwritten or because it was removed by
+    /// [`cleanup`](Girlboss::cleanup).
+    ///
+    /// A job that is overwritten by a job with
+    /// [`ConflictPolicy::Queue`](crate::ConflictPolicy::Queue) or
+    /// [`ConflictPolicy::Replace`](crate::ConflictPolicy::Replace) may still
+    /// be running. It stays reachable through the new job, which waits for
+    /// it, and is cancelled along with it by a later `Replace`.
+    Evicted {
+        /// The ID of the job.
+        id: K,
+        /// The job's monitor.
+        monitor: Monitor,
+    },
+}
+
+impl<K> Event<K> {
+    /// Returns the ID of the job that this event is about.
+    pub fn id(&self) -> &K {
+        match self {
+            Event::Started { id, .. }
+            | Event::Reported { id, .. }
+            | Event::Finished { id, .. }
+            | Event::Evicted { id, .. } => id,
+        }
+    }
+}
+
+/// A [`Stream`] of [`Event`]s, returned by [`Girlboss::subscribe`].
+#[derive(Debug)]
+#[must_use = "streams do nothing unless polled"]
+pub struct EventStream<K>(mpsc::UnboundedReceiver<Event<K>>);
+
+impl<K> Stream for EventStream<K> {
+    type Item = Event<K>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Event<K>>> {
+        Pin::new(&mut self.0).poll_next(cx)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.0.size_hint()
+    }
+}
+
+/// Something that is notified about jobs entering and leaving a job manager.
+///
+/// This is type-erased so that the job manager does not need any extra bounds
+/// on the job ID type, except in the methods that add observers.
+pub(crate) trait Observer<K>: Send + Sync {
+    /// Called when a job is started, before it is spawned.
+    fn started(&self, id: &K, monitor: &Monitor);
+
+    /// Called when a job is removed from the job manager.
+    fn evicted(&self, id: &K, monitor: &Monitor);
+
+    /// Returns `true` if this observer no longer needs to be notified.
+    fn is_closed(&self) -> bool {
+        false
+    }
+}
+
+type Sink<K> = Arc<dyn Fn(Event<K>) + Send + Sync>;
+
+/// An [`Observer`] that turns everything into [`Event`]s.
+struct EventObserver<K> {
+    sink: Sink<K>,
+    is_closed: Box<dyn Fn() -> bool + Send + Sync>,
+}
+
+impl<K: Clone + Send + Sync + 'static> Observer<K> for EventObserver<K> {
+    fn started(&self, id: &K, monitor: &Monitor) {
+        (self.sink)(Event::Started {
+            id: id.clone(),
+            monitor: monitor.clone(),
+        });
+
+        // The job can outlive the job manager, so only hold on to the sink
+        // weakly. Otherwise, an event stream wouldn't end until every job
+        // that was started while subscribed is dropped.
+        let id = id.clone();
+        let sink = Arc::downgrade(&self.sink);
+        monitor.add_listener(Arc::new(move |monitor, event| {
+            let Some(sink) = Weak::upgrade(&sink) else {
+                return;
+            };
+            match event {
+                MonitorEvent::Reported(status) => sink(Event::Reported {
+                    id: id.clone(),
+                    status: status.clone(),
+                }),
+                MonitorEvent::Finished => sink(Event::Finished {
+                    id: id.clone(),
+                    outcome: monitor.succeeded(),
+                    monitor: monitor.clone(),
+                }),
+                MonitorEvent::Running => {}
+            }
+        }));
+    }
+
+    fn evicted(&self, id: &K, monitor: &Monitor) {
+        (self.sink)(Event::Evicted {
+            id: id.clone(),
+            monitor: monitor.clone(),
+        });
+    }
+
+    fn is_closed(&self) -> bool {
+        (self.is_closed)()
+    }
+}
+
+impl<K, V> Girlboss<K, V>
+where
+    K: Ord + Clone + Send + Sync + 'static,
+    V: AsRef<Monitor> + Clone,
+{
+    /// Returns a [`Stream`] of the lifecycle [`Event`]s of all jobs that are
+    /// started by this job manager from now on.
+    ///
+    /// Events are buffered without limit until they are consumed. Dropping
+    /// the stream unsubscribes from the events, and the stream ends once the
+    /// job manager is dropped.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// use futures::StreamExt;
+    /// use girlboss::Event;
+    /// use girlboss::tokio::Girlboss;
+    ///
+    /// let mut manager = Girlboss::<i32>::new();
+    /// let mut events = manager.subscribe();
+    ///
+    /// manager.start(1, |mon| async move {
+    ///     write!(mon, "Working");
+    /// });
+    ///
+    /// assert!(matches!(events.next().await, Some(Event::Started { id: 1, .. })));
+    /// assert!(matches!(events.next().await, Some(Event::Reported { id: 1, .. })));
+    /// assert!(matches!(
+    ///     events.next().await,
+    ///     Some(Event::Finished { id: 1, outcome: true, .. }),
+    /// ));
+    /// # }
+    /// ```
+    pub fn subscribe(&mut self) -> EventStream<K> {
+        let (sender, receiver) = mpsc::unbounded();
+        let closed_sender = sender.clone();
+        self.observers.push(Box::new(EventObserver {
+            sink: Arc::new(move |event| {
+                // The receiver may have been dropped, but that's fine.
+                let _ = sender.unbounded_send(event);
+            }),
+            is_closed: Box::new(move || closed_sender.is_closed()),
+        }));
+        EventStream(receiver)
+    }
+
+    /// Calls `callback` with the lifecycle [`Event`]s of all jobs that are
+    /// started by this job manager from now on.
+    ///
+    /// The callback is called synchronously, from wherever the event happens.
+    /// For example, [`Event::Reported`] and [`Event::Finished`] are emitted
+    /// from the job's own task, whereas [`Event::Started`] and
+    /// [`Event::Evicted`] are emitted from the caller of the job manager
+    /// method that started or evicted the job. As such, the callback should
+    /// be quick, and it must not lock the job manager if the job manager is
+    /// wrapped in a lock. Once the job manager is dropped, the callback is
+    /// no longer called.
+    pub fn on_event(&mut self, callback: impl Fn(&Event<K>) + Send + Sync + 'static) {
+        self.observers.push(Box::new(EventObserver {
+            sink: Arc::new(move |event| callback(&event)),
+            is_closed: Box::new(|| false),
+        }));
+    }
+
+    /// Calls `callback` with the ID and monitor of every job started by this
+    /// job manager from now on, when that job finishes.
+    ///
+    /// This is a shorthand for [`on_event`](Self::on_event) that only handles
+    /// [`Event::Finished`]. The callback is called from the job's own task, so
+    /// the same caveats apply.
+    pub fn on_finish(&mut self, callback: impl Fn(&K, &Monitor) + Send + Sync + 'static) {
+        self.on_event(move |event| {
+            if let Event::Finished { id, monitor, .. } = event {
+                callback(id, monitor);
+            }
+        });
+    }
+}
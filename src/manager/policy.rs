@@ -0,0 +1,37 @@
+/// What a [`Girlboss`](crate::Girlboss) does when a job is started with the
+/// ID of a job that is still in progress.
+///
+/// The policy can be set for the whole job manager with
+/// [`set_conflict_policy`](crate::Girlboss::set_conflict_policy), or for a
+/// single call with
+/// [`start_with_policy`](crate::Girlboss::start_with_policy).
+///
+/// Regardless of the policy, a job whose ID belongs to a **finished** job is
+/// always started right away, overwriting the finished job (unless the policy
+/// is [`KeepHistory`](Self::KeepHistory), in which case the finished job is
+/// kept in the history).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[non_exhaustive]
+pub enum ConflictPolicy {
+    /// Don't start the new job, and return
+    /// <code>Err([Error::JobExists](crate::Error::JobExists))</code> instead.
+    ///
+    /// This is the default.
+    #[default]
+    Reject,
+    /// [Cancel](crate::Monitor::cancel) the running job, and start the new job
+    /// once the old job has stopped.
+    ///
+    /// If the running job was itself started with [`Queue`](Self::Queue) or
+    /// `Replace`, then the jobs it is waiting for are cancelled too, and the
+    /// new job only starts once all of them have stopped.
+    ///
+    /// Blocking jobs can't be cancelled, so replacing a blocking job that is
+    /// still in progress behaves like `Queue`.
+    Replace,
+    /// Start the new job once the running job has finished.
+    Queue,
+    /// Start the new job right away, alongside the running job. The running
+    /// job is moved into the [history](crate::Girlboss::history).
+    KeepHistory,
+}
@@ -0,0 +1,182 @@
+use std::borrow::Borrow;
+use std::cmp::Reverse;
+use std::ops::{Bound, RangeBounds};
+
+use crate::{Girlboss, Monitor};
+
+/// A filter on the state of a job, used when listing jobs in a [`Girlboss`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[non_exhaustive]
+pub enum JobFilter {
+    /// Matches every job.
+    #[default]
+    All,
+    /// Matches jobs that are still in progress.
+    Running,
+    /// Matches jobs that are finished, whether they succeeded or not.
+    Finished,
+    /// Matches jobs that finished successfully.
+    Succeeded,
+    /// Matches jobs that finished unsuccessfully.
+    Failed,
+}
+
+impl JobFilter {
+    /// Returns whether the job with the given monitor matches this filter.
+    pub fn matches(self, monitor: &Monitor) -> bool {
+        match self {
+            JobFilter::All => true,
+            JobFilter::Running => monitor.outcome().is_none(),
+            JobFilter::Finished => monitor.outcome().is_some(),
+            JobFilter::Succeeded => monitor.outcome() == Some(true),
+            JobFilter::Failed => monitor.outcome() == Some(false),
+        }
+    }
+}
+
+/// The order in which [`Girlboss::sorted`] returns jobs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum SortBy {
+    /// Most recently started jobs first.
+    StartedAt,
+    /// Most recently finished jobs first. Jobs that are still in progress come
+    /// before all finished jobs.
+    FinishedAt,
+}
+
+/// A page of jobs, returned by [`Girlboss::page`].
+#[derive(Debug, Clone)]
+pub struct Page<'a, K, V> {
+    items: Vec<(&'a K, &'a V)>,
+    next_cursor: Option<&'a K>,
+}
+
+impl<'a, K, V> Page<'a, K, V> {
+    /// The jobs in this page, ordered by ID.
+    pub fn items(&self) -> &[(&'a K, &'a V)] {
+        &self.items
+    }
+
+    /// Consumes the page, returning the jobs in it.
+    pub fn into_items(self) -> Vec<(&'a K, &'a V)> {
+        self.items
+    }
+
+    /// The cursor to pass to [`Girlboss::page`] to get the next page, or
+    /// `None` if this is the last page.
+    pub fn next_cursor(&self) -> Option<&'a K> {
+        self.next_cursor
+    }
+}
+
+/// Methods to list the jobs in a job manager.
+impl<K: Ord, V: AsRef<Monitor> + Clone> Girlboss<K, V> {
+    /// Returns the number of jobs in this job manager, including finished ones.
+    pub fn len(&self) -> usize {
+        self.jobs.len()
+    }
+
+    /// Returns `true` if this job manager contains no jobs.
+    pub fn is_empty(&self) -> bool {
+        self.jobs.is_empty()
+    }
+
+    /// Iterates over all jobs and their IDs, ordered by ID.
+    pub fn iter(&self) -> impl DoubleEndedIterator<Item = (&K, &V)> + '_ {
+        self.jobs.iter().map(|(id, slot)| (id, &slot.current))
+    }
+
+    /// Iterates over the jobs whose IDs are within `range`, ordered by ID.
+    ///
+    /// # Panics
+    ///
+    /// Panics under the same conditions as [`BTreeMap::range`], that is, if
+    /// the start of the range is greater than the end, or if the start and end
+    /// are equal and both are excluded.
+    ///
+    /// [`BTreeMap::range`]: std::collections::BTreeMap::range
+    pub fn range<Q, B>(&self, range: B) -> impl DoubleEndedIterator<Item = (&K, &V)> + '_
+    where
+        Q: Ord + ?Sized,
+        K: Borrow<Q>,
+        B: RangeBounds<Q>,
+    {
+        self.jobs.range(range).map(|(id, slot)| (id, &slot.current))
+    }
+
+    /// Iterates over the jobs that match `filter`, ordered by ID.
+    pub fn filter(&self, filter: JobFilter) -> impl DoubleEndedIterator<Item = (&K, &V)> + '_ {
+        self.iter()
+            .filter(move |(_, job)| filter.matches(job.as_ref()))
+    }
+
+    /// Returns the jobs that match `filter`, sorted by time as specified by
+    /// `sort`.
+    ///
+    /// Jobs with the same time are ordered by ID.
+    pub fn sorted(&self, filter: JobFilter, sort: SortBy) -> Vec<(&K, &V)> {
+        let mut jobs: Vec<_> = self.filter(filter).collect();
+        match sort {
+            SortBy::StartedAt => {
+                jobs.sort_by_key(|(_, job)| Reverse(job.as_ref().started_at()));
+            }
+            SortBy::FinishedAt => {
+                // `None` sorts before `Some`, so running jobs come first.
+                jobs.sort_by_key(|(_, job)| job.as_ref().finished_at().map(Reverse));
+            }
+        }
+        jobs
+    }
+
+    /// Returns up to `limit` jobs that match `filter`, ordered by ID, starting
+    /// right after the ID `after`.
+    ///
+    /// To get the first page, pass `None` as `after`. To get subsequent
+    /// pages, pass the previous page's [`next_cursor`](Page::next_cursor).
+    /// Since the cursor is a job ID, pages stay consistent even if jobs are
+    /// added or removed between requests.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// use girlboss::JobFilter;
+    /// use girlboss::tokio::Girlboss;
+    ///
+    /// let mut manager = Girlboss::<u32>::new();
+    /// for id in 0..5_u32 {
+    ///     manager.start(id, |_| async {}).unwrap();
+    /// }
+    ///
+    /// let page = manager.page(None, 2, JobFilter::All);
+    /// assert_eq!(page.items().len(), 2);
+    /// let page = manager.page(page.next_cursor(), 2, JobFilter::All);
+    /// assert_eq!(page.items().len(), 2);
+    /// let page = manager.page(page.next_cursor(), 2, JobFilter::All);
+    /// assert_eq!(page.items().len(), 1);
+    /// assert_eq!(page.next_cursor(), None);
+    /// # }
+    /// ```
+    pub fn page<Q>(&self, after: Option<&Q>, limit: usize, filter: JobFilter) -> Page<'_, K, V>
+    where
+        Q: Ord + ?Sized,
+        K: Borrow<Q>,
+    {
+        let start = match after {
+            Some(after) => Bound::Excluded(after),
+            None => Bound::Unbounded,
+        };
+        let mut jobs = self
+            .range::<Q, _>((start, Bound::Unbounded))
+            .filter(|(_, job)| filter.matches(job.as_ref()));
+
+        let items: Vec<_> = jobs.by_ref().take(limit).collect();
+        let next_cursor = match (items.last(), jobs.next()) {
+            (Some((last, _)), Some(_)) => Some(*last),
+            _ => None,
+        };
+        Page { items, next_cursor }
+    }
+}
@@ -0,0 +1,32 @@
+use std::fmt::Debug;
+
+use super::events::Observer;
+use crate::{Girlboss, Monitor};
+
+/// An [`Observer`] that records job IDs in the jobs' spans.
+struct SpanObserver;
+
+impl<K: Debug> Observer<K> for SpanObserver {
+    fn started(&self, id: &K, monitor: &Monitor) {
+        monitor.span().record("id", tracing::field::debug(id));
+    }
+
+    fn evicted(&self, _id: &K, _monitor: &Monitor) {}
+}
+
+impl<K, V> Girlboss<K, V>
+where
+    K: Ord + Debug,
+    V: AsRef<Monitor> + Clone,
+{
+    /// Records the ID of every job that is started by this job manager from
+    /// now on in the job's span, as the `id` field. See the
+    /// [`trace`](crate::trace) module for more information.
+    ///
+    /// Calling this more than once has no further effect.
+    pub fn record_span_ids(&mut self) {
+        if !std::mem::replace(&mut self.records_span_ids, true) {
+            self.observers.push(Box::new(SpanObserver));
+        }
+    }
+}
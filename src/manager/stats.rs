@@ -0,0 +1,268 @@
+use std::fmt::{self, Write};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+
+use super::events::Observer;
+use crate::metric;
+use crate::monitor::MonitorEvent;
+use crate::{Girlboss, Monitor};
+
+/// The upper bounds of the buckets of the job duration histogram.
+const BUCKETS: [Duration; 10] = [
+    Duration::from_millis(100),
+    Duration::from_millis(500),
+    Duration::from_secs(1),
+    Duration::from_secs(5),
+    Duration::from_secs(10),
+    Duration::from_secs(30),
+    Duration::from_secs(60),
+    Duration::from_secs(300),
+    Duration::from_secs(1800),
+    Duration::from_secs(3600),
+];
+
+/// The counters of a job manager, which are updated as its jobs progress so
+/// that reading them doesn't need to look at the jobs.
+#[derive(Default)]
+pub(super) struct Counters {
+    started: AtomicU64,
+    succeeded: AtomicU64,
+    failed: AtomicU64,
+    running: AtomicU64,
+    queued: AtomicU64,
+    /// The total duration of finished jobs, in nanoseconds.
+    duration_sum: AtomicU64,
+    /// The number of finished jobs in each bucket, not including the jobs in
+    /// lower buckets.
+    duration_buckets: [AtomicU64; BUCKETS.len()],
+}
+
+impl Counters {
+    fn finished(&self, monitor: &Monitor) {
+        if monitor.began_running() {
+            self.running.fetch_sub(1, Ordering::Relaxed);
+        } else {
+            self.queued.fetch_sub(1, Ordering::Relaxed);
+        }
+        if monitor.succeeded() {
+            self.succeeded.fetch_add(1, Ordering::Relaxed);
+        } else {
+            self.failed.fetch_add(1, Ordering::Relaxed);
+        }
+
+        let elapsed = monitor.elapsed();
+        let nanos = u64::try_from(elapsed.as_nanos()).unwrap_or(u64::MAX);
+        self.duration_sum.fetch_add(nanos, Ordering::Relaxed);
+        if let Some(bucket) = BUCKETS.iter().position(|&bound| elapsed <= bound) {
+            self.duration_buckets[bucket].fetch_add(1, Ordering::Relaxed);
+        }
+    }
+
+    /// Reads the counters into a [`Stats`].
+    fn snapshot(&self) -> Stats {
+        let mut total = 0;
+        let duration_buckets = BUCKETS
+            .iter()
+            .zip(&self.duration_buckets)
+            .map(|(&bound, count)| {
+                total += count.load(Ordering::Relaxed);
+                (bound, total)
+            })
+            .collect();
+        Stats {
+            started: self.started.load(Ordering::Relaxed),
+            succeeded: self.succeeded.load(Ordering::Relaxed),
+            failed: self.failed.load(Ordering::Relaxed),
+            running: self.running.load(Ordering::Relaxed),
+            queued: self.queued.load(Ordering::Relaxed),
+            duration_sum: Duration::from_nanos(self.duration_sum.load(Ordering::Relaxed)),
+            duration_buckets,
+        }
+    }
+}
+
+/// An [`Observer`] that keeps the [`Counters`] up to date.
+pub(super) struct StatsObserver(pub(super) Arc<Counters>);
+
+impl<K> Observer<K> for StatsObserver {
+    fn started(&self, _id: &K, monitor: &Monitor) {
+        self.0.started.fetch_add(1, Ordering::Relaxed);
+        self.0.queued.fetch_add(1, Ordering::Relaxed);
+
+        let counters = self.0.clone();
+        monitor.add_listener(Arc::new(move |monitor, event| match event {
+            MonitorEvent::Running => {
+                counters.queued.fetch_sub(1, Ordering::Relaxed);
+                counters.running.fetch_add(1, Ordering::Relaxed);
+            }
+            MonitorEvent::Finished => counters.finished(monitor),
+            MonitorEvent::Reported(_) => {}
+        }));
+    }
+
+    fn evicted(&self, _id: &K, _monitor: &Monitor) {}
+}
+
+/// A snapshot of the statistics of the jobs started by a job manager,
+/// returned by [`Girlboss::stats`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct Stats {
+    /// The number of jobs that were started.
+    pub started: u64,
+    /// The number of jobs that finished successfully.
+    pub succeeded: u64,
+    /// The number of jobs that failed, panicked or were cancelled.
+    pub failed: u64,
+    /// The number of jobs that are running.
+    pub running: u64,
+    /// The number of jobs that are waiting for their predecessor, or haven't
+    /// been polled yet.
+    pub queued: u64,
+    /// The total duration of the finished jobs.
+    pub duration_sum: Duration,
+    /// A histogram of the durations of the finished jobs, as pairs of an
+    /// upper bound and the number of jobs that took at most that long.
+    pub duration_buckets: Vec<(Duration, u64)>,
+}
+
+impl Stats {
+    /// Returns the number of jobs that finished.
+    pub fn finished(&self) -> u64 {
+        self.succeeded + self.failed
+    }
+
+    /// Renders these statistics in the Prometheus text exposition format.
+    ///
+    /// The metric names are the same as the ones recorded through the
+    /// `metrics` crate.
+    pub fn render_prometheus(&self) -> String {
+        let mut out = String::new();
+
+        header(&mut out, metric::STARTED, "counter", "Jobs started.");
+        sample(&mut out, metric::STARTED, "", self.started);
+
+        header(&mut out, metric::FINISHED, "counter", "Jobs finished.");
+        for (succeeded, value) in [(true, self.succeeded), (false, self.failed)] {
+            let labels = format!("outcome=\"{}\"", metric::outcome(succeeded));
+            sample(&mut out, metric::FINISHED, &labels, value);
+        }
+
+        header(&mut out, metric::RUNNING, "gauge", "Jobs running.");
+        sample(&mut out, metric::RUNNING, "", self.running);
+
+        header(&mut out, metric::QUEUED, "gauge", "Jobs waiting to run.");
+        sample(&mut out, metric::QUEUED, "", self.queued);
+
+        let name = metric::DURATION;
+        header(&mut out, name, "histogram", "Job durations in seconds.");
+        for &(bound, count) in &self.duration_buckets {
+            let labels = format!("le=\"{}\"", bound.as_secs_f64());
+            sample(&mut out, &format!("{name}_bucket"), &labels, count);
+        }
+        let finished = self.finished();
+        sample(&mut out, &format!("{name}_bucket"), "le=\"+Inf\"", finished);
+        let sum = self.duration_sum.as_secs_f64();
+        let _ = writeln!(out, "{name}_sum {sum}");
+        sample(&mut out, &format!("{name}_count"), "", finished);
+
+        out
+    }
+}
+
+fn header(out: &mut String, name: &str, kind: &str, help: &str) {
+    let _ = writeln!(out, "# HELP {name} {help}");
+    let _ = writeln!(out, "# TYPE {name} {kind}");
+}
+
+fn sample(out: &mut String, name: &str, labels: &str, value: u64) {
+    if labels.is_empty() {
+        let _ = writeln!(out, "{name} {value}");
+    } else {
+        let _ = writeln!(out, "{name}{{{labels}}} {value}");
+    }
+}
+
+impl<K: Ord, V: AsRef<Monitor> + Clone> Girlboss<K, V> {
+    /// Returns statistics about the jobs started by this job manager,
+    /// including jobs that were removed since.
+    ///
+    /// These are kept up to date as jobs progress, so this doesn't need to
+    /// look at the stored jobs.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// use girlboss::tokio::Girlboss;
+    ///
+    /// let mut manager = Girlboss::<i32>::new();
+    /// manager.start(1, |_| async {}).unwrap().wait().await.unwrap();
+    ///
+    /// let stats = manager.stats();
+    /// assert_eq!(stats.started, 1);
+    /// assert_eq!(stats.succeeded, 1);
+    /// assert_eq!(stats.running, 0);
+    /// # }
+    /// ```
+    pub fn stats(&self) -> Stats {
+        self.stats.snapshot()
+    }
+
+    /// Renders the [`stats`](Self::stats) of this job manager in the
+    /// Prometheus text exposition format.
+    pub fn render_prometheus(&self) -> String {
+        self.stats().render_prometheus()
+    }
+
+    /// Returns a handle to the statistics of this job manager, which can be
+    /// read without access to the manager.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// use girlboss::tokio::Girlboss;
+    ///
+    /// let mut manager = Girlboss::<i32>::new();
+    /// let stats = manager.stats_handle();
+    ///
+    /// manager.start(1, |_| async {}).unwrap().wait().await.unwrap();
+    /// assert_eq!(stats.stats().succeeded, 1);
+    /// # }
+    /// ```
+    pub fn stats_handle(&self) -> StatsHandle {
+        StatsHandle(self.stats.clone())
+    }
+}
+
+/// A handle to the statistics of a job manager, returned by
+/// [`Girlboss::stats_handle`].
+///
+/// It can be cloned and kept, for example by a metrics endpoint, so that the
+/// statistics can be read without locking the manager.
+#[derive(Clone)]
+pub struct StatsHandle(Arc<Counters>);
+
+impl StatsHandle {
+    /// Returns statistics about the jobs started by the job manager. See
+    /// [`Girlboss::stats`].
+    pub fn stats(&self) -> Stats {
+        self.0.snapshot()
+    }
+
+    /// Renders the [`stats`](Self::stats) in the Prometheus text exposition
+    /// format.
+    pub fn render_prometheus(&self) -> String {
+        self.stats().render_prometheus()
+    }
+}
+
+impl fmt::Debug for StatsHandle {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("StatsHandle").field(&self.stats()).finish()
+    }
+}
@@ -0,0 +1,49 @@
+//! Job metrics, recorded through the [`metrics`] facade.
+//!
+//! The same names are used by [`Stats::render_prometheus`](crate::Stats::render_prometheus).
+
+use crate::Monitor;
+
+/// Counter of jobs that were spawned.
+pub(crate) const STARTED: &str = "girlboss_jobs_started_total";
+/// Counter of jobs that finished, labeled by `outcome`.
+pub(crate) const FINISHED: &str = "girlboss_jobs_finished_total";
+/// Gauge of jobs that are running.
+pub(crate) const RUNNING: &str = "girlboss_jobs_running";
+/// Gauge of jobs that are waiting for their predecessor.
+pub(crate) const QUEUED: &str = "girlboss_jobs_queued";
+/// Histogram of the time from starting a job until it finished, in seconds.
+pub(crate) const DURATION: &str = "girlboss_job_duration_seconds";
+
+/// Returns the `outcome` label of a finished job.
+pub(crate) fn outcome(succeeded: bool) -> &'static str {
+    if succeeded {
+        "succeeded"
+    } else {
+        "failed"
+    }
+}
+
+/// Records that a job was spawned.
+pub(crate) fn spawned() {
+    metrics::counter!(STARTED).increment(1);
+    metrics::gauge!(QUEUED).increment(1);
+}
+
+/// Records that a job began running, after waiting for its predecessor.
+pub(crate) fn running() {
+    metrics::gauge!(QUEUED).decrement(1);
+    metrics::gauge!(RUNNING).increment(1);
+}
+
+/// Records that a job finished.
+pub(crate) fn finished(monitor: &Monitor) {
+    if monitor.began_running() {
+        metrics::gauge!(RUNNING).decrement(1);
+    } else {
+        metrics::gauge!(QUEUED).decrement(1);
+    }
+    let outcome = outcome(monitor.succeeded());
+    metrics::counter!(FINISHED, "outcome" => outcome).increment(1);
+    metrics::histogram!(DURATION, "outcome" => outcome).record(monitor.elapsed());
+}
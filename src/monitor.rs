@@ -1,9 +1,20 @@
+use std::collections::btree_map::Entry;
+use std::collections::BTreeMap;
 use std::fmt;
-use std::sync::{Arc, OnceLock};
+use std::future::Future;
+use std::panic::AssertUnwindSafe;
+use std::pin::Pin;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex, MutexGuard, OnceLock, PoisonError, RwLock};
+use std::task::{Context, Poll, Waker};
+use std::thread;
 use std::time::{Duration, Instant};
 
+use futures::future::{AbortHandle, AbortRegistration};
+
+use crate::runtime::{self, ThreadWaker};
 use crate::status::AtomicJobStatus;
-use crate::{JobReturnStatus, JobStatus};
+use crate::{Error, JobReturnStatus, JobStatus, Result};
 
 /// Stores progress data for a job.
 ///
@@ -13,8 +24,9 @@ use crate::{JobReturnStatus, JobStatus};
 /// it [`started_at`](Self::started_at) or [`finished_at`](Self::finished_at),
 /// and so on.
 ///
-/// Monitors are not created directly; instead, they are automatically created
-/// alongside a [`Job`](crate::common::Job).
+/// Monitors are usually not created directly; instead, they are automatically
+/// created alongside a [`Job`](crate::common::Job). Work that is spawned
+/// outside of this crate can use a [`detached`](Self::detached) monitor.
 ///
 /// Cloning a `Monitor` is cheap. The cloned `Monitor` represents the same
 /// monitor as the original, so both can be used to query the same job's status.
@@ -47,15 +59,99 @@ struct MonitorInner {
     status: AtomicJobStatus,
     started_at: Instant,
     finished: OnceLock<JobFinishedInfo>,
+    /// Whether a job has been spawned with this monitor.
+    attached: AtomicBool,
+    /// Whether the job has begun running, after waiting for its predecessor.
+    began_running: AtomicBool,
+    /// Tasks waiting for the job to finish.
+    waiters: Mutex<Waiters>,
+    /// Used to cancel the job.
+    abort_handle: OnceLock<AbortHandle>,
+    /// A job that must finish before this job begins.
+    predecessor: Mutex<Option<Monitor>>,
+    /// Callbacks for status reports and job completion.
+    listeners: RwLock<Vec<Listener>>,
+    /// Callbacks to run once when the job finishes.
+    hooks: Mutex<Vec<Hook>>,
+    /// The job's span, which is entered while the job runs.
+    #[cfg(feature = "tracing")]
+    span: tracing::Span,
+}
+
+/// Something that happened to a job, passed to a [`Listener`].
+#[derive(Clone, Copy)]
+pub(crate) enum MonitorEvent<'a> {
+    /// A new status was reported.
+    Reported(&'a JobStatus),
+    /// The job began running, after waiting for its predecessor.
+    Running,
+    /// The job finished.
+    Finished,
 }
 
+/// A callback for the events of a job.
+pub(crate) type Listener = Arc<dyn Fn(&Monitor, MonitorEvent<'_>) + Send + Sync>;
+
+/// A callback that runs once the job finishes.
+type Hook = Box<dyn FnOnce(&Monitor) + Send>;
+
 #[derive(Debug)]
 struct JobFinishedInfo {
     finished_at: Instant,
     is_success: bool,
 }
 
+/// The wakers of the [`Finished`] futures waiting for a job, keyed so that
+/// each future can remove its own waker when it is dropped.
+#[derive(Default)]
+struct Waiters {
+    next_key: u64,
+    wakers: BTreeMap<u64, Waker>,
+}
+
 impl Monitor {
+    /// Creates a monitor that doesn't belong to a job yet.
+    ///
+    /// This is for work that is spawned outside of this crate. Pass the
+    /// monitor to the work so that it can report its progress, then track the
+    /// work's handle with [`Job::adopt`](crate::common::Job::adopt), which
+    /// records how the work went in this monitor.
+    pub fn detached() -> Monitor {
+        Monitor::starting()
+    }
+
+    /// Returns the monitor of the job that is currently running, or `None` if
+    /// this is not called from inside a job.
+    ///
+    /// This lets code deep inside a job report its progress without having
+    /// the monitor passed to it. See also the [`report!`](crate::report!)
+    /// macro. Tasks and threads spawned by a job don't count as being inside
+    /// the job.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// use girlboss::Monitor;
+    /// use girlboss::tokio::Job;
+    ///
+    /// fn helper() {
+    ///     if let Some(mon) = Monitor::current() {
+    ///         write!(mon, "Reported by a helper");
+    ///     }
+    /// }
+    ///
+    /// let job = Job::start(|_| async { helper() });
+    /// job.wait().await.unwrap();
+    /// assert_eq!(job.status().message(), "Reported by a helper");
+    /// assert_eq!(Monitor::current(), None);
+    /// # }
+    /// ```
+    pub fn current() -> Option<Monitor> {
+        runtime::current_monitor()
+    }
+
     /// Reports a new status message to this `Monitor`.
     ///
     /// If your message is already a [`String`] and you are able to give
@@ -63,7 +159,11 @@ impl Monitor {
     /// to using [`write!`]. However, if your message is a `&str` or needs to be
     /// [`format`]ted, then you should use [`write!`].
     pub fn report(&self, status: impl Into<JobStatus>) {
-        self.0.status.store(status.into());
+        let status = status.into();
+        self.0.status.store(status.clone());
+        #[cfg(feature = "tracing")]
+        crate::trace::reported(self, &status);
+        self.notify(MonitorEvent::Reported(&status));
     }
 
     /// Implementation to allow use with [`write!`].
@@ -141,6 +241,127 @@ impl Monitor {
     pub fn elapsed(&self) -> Duration {
         self.finished_at().unwrap_or_else(Instant::now) - self.0.started_at
     }
+
+    /// Requests that the job be cancelled.
+    ///
+    /// The job stops the next time it yields at an `.await`, after which it
+    /// is considered finished and failed, with the status message "The job was
+    /// cancelled". Jobs that are already finished are unaffected.
+    ///
+    /// Note that cancellation is not immediate, so the job may still be in
+    /// progress right after this method returns.
+    pub fn cancel(&self) {
+        if let Some(abort_handle) = self.0.abort_handle.get() {
+            abort_handle.abort();
+        }
+    }
+
+    /// Returns a future that resolves once the job is finished, to whether the
+    /// job succeeded (see [`outcome`](Self::outcome)).
+    ///
+    /// Unlike [`Job::wait`](crate::common::Job::wait), this does not depend on
+    /// any async runtime, so the future can be awaited from any thread or
+    /// runtime, including one other than the job's. This makes it possible to
+    /// wait for jobs in a [`Girlboss`](crate::Girlboss) that only stores
+    /// monitors.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// use girlboss::{Girlboss, Monitor};
+    /// use girlboss::runtime::Tokio;
+    ///
+    /// let mut manager: Girlboss<i32, Monitor> = Girlboss::new();
+    /// manager.start::<Tokio, _, _>(1, |_| async {}).unwrap();
+    ///
+    /// let monitor = manager.get(&1).unwrap();
+    /// assert_eq!(monitor.finished().await, true);
+    /// # }
+    /// ```
+    pub fn finished(&self) -> Finished {
+        Finished {
+            monitor: self.clone(),
+            key: None,
+        }
+    }
+
+    /// Blocks the current thread until the job is finished.
+    ///
+    /// This is meant for synchronous code that is not running in an async
+    /// runtime, and it works regardless of which runtime the job runs on. In
+    /// async code, use [`finished`](Self::finished) instead.
+    ///
+    /// If the job failed, this returns <code>Err([Error::JobFailed])</code>.
+    /// Otherwise, it returns `Ok(())`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if called from inside a job, or from a thread that is driving a
+    /// Tokio runtime (such as the body of `#[tokio::main]`) or an actix-rt
+    /// arbiter, since blocking it would stall the async runtime and might
+    /// never finish. Tokio's blocking threads, such as the ones used by
+    /// `spawn_blocking`, are fine.
+    ///
+    /// Other async contexts, such as Tokio tasks that aren't jobs, a smol
+    /// executor or a `futures` executor, can't be detected, so calling this
+    /// from them can deadlock instead.
+    pub fn wait_blocking(&self) -> Result<()> {
+        self.block_until(None)
+    }
+
+    /// Like [`wait_blocking`](Self::wait_blocking), but gives up and returns
+    /// <code>Err([Error::Timeout])</code> if the job is not finished after
+    /// `timeout`.
+    ///
+    /// # Panics
+    ///
+    /// Panics in the same cases as [`wait_blocking`](Self::wait_blocking).
+    pub fn wait_blocking_timeout(&self, timeout: Duration) -> Result<()> {
+        self.block_until(Some(Instant::now() + timeout))
+    }
+
+    /// Registers a callback to run when the job finishes.
+    ///
+    /// The callback receives the job's [`outcome`](Self::outcome) and its
+    /// final [`status`](Self::status). It runs in the job's own task, right
+    /// after the job is recorded as finished, which includes jobs that
+    /// panicked or were [cancelled](Self::cancel). If the job is already
+    /// finished, then the callback runs immediately instead.
+    ///
+    /// The callback should be quick, since it holds up the job's task. If it
+    /// panics, the panic is caught, and the job and its other callbacks are
+    /// unaffected.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// use girlboss::tokio::Job;
+    /// use tokio::sync::oneshot;
+    ///
+    /// let job = Job::start(|_| async { Err::<(), _>("Out of coffee") });
+    /// let (sender, receiver) = oneshot::channel();
+    /// job.on_finish(move |outcome, status| {
+    ///     let _ = sender.send(format!("{outcome}: {}", status.message()));
+    /// });
+    /// assert_eq!(receiver.await.unwrap(), "false: Out of coffee");
+    /// # }
+    /// ```
+    pub fn on_finish(&self, callback: impl FnOnce(bool, JobStatus) + Send + 'static) {
+        self.add_hook(Box::new(move |monitor| {
+            callback(monitor.succeeded(), monitor.status())
+        }));
+    }
+
+    /// Returns the job's [`tracing`] span, which is entered while the job
+    /// runs. See the [`trace`](crate::trace) module for more information.
+    #[cfg(feature = "tracing")]
+    pub fn span(&self) -> &tracing::Span {
+        &self.0.span
+    }
 }
 
 // Internal methods
@@ -150,9 +371,124 @@ impl Monitor {
             status: AtomicJobStatus::new("Starting job".into()),
             started_at: Instant::now(),
             finished: OnceLock::new(),
+            attached: AtomicBool::new(false),
+            began_running: AtomicBool::new(false),
+            waiters: Mutex::new(Waiters::default()),
+            abort_handle: OnceLock::new(),
+            predecessor: Mutex::new(None),
+            listeners: RwLock::new(Vec::new()),
+            hooks: Mutex::new(Vec::new()),
+            #[cfg(feature = "tracing")]
+            span: crate::trace::job_span(),
         }))
     }
 
+    /// Adds a callback that runs once when the job finishes, or right away if
+    /// the job is already finished.
+    pub(crate) fn add_hook(&self, hook: Hook) {
+        let mut hooks = self.0.hooks.lock().unwrap_or_else(PoisonError::into_inner);
+        // `set_finished` records the completion before taking the hooks, so if
+        // the job isn't finished yet, the hook is guaranteed to be run later.
+        if !self.is_finished() {
+            hooks.push(hook);
+            return;
+        }
+        drop(hooks);
+        self.run_hook(hook);
+    }
+
+    /// Runs a hook, containing any panic so that it doesn't affect the other
+    /// hooks, the job's task, or the caller of [`add_hook`](Self::add_hook).
+    fn run_hook(&self, hook: Hook) {
+        let _ = std::panic::catch_unwind(AssertUnwindSafe(|| hook(self)));
+    }
+
+    /// Adds a callback that is called whenever a status is reported, when the
+    /// job begins running, and when the job finishes.
+    pub(crate) fn add_listener(&self, listener: Listener) {
+        self.0
+            .listeners
+            .write()
+            .unwrap_or_else(PoisonError::into_inner)
+            .push(listener);
+    }
+
+    fn notify(&self, event: MonitorEvent<'_>) {
+        // Call the listeners without holding the lock, so that they can use
+        // this monitor, e.g. to report a status or add another listener.
+        let listeners = self
+            .0
+            .listeners
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .clone();
+        for listener in &listeners {
+            listener(self, event);
+        }
+    }
+
+    /// Makes this job wait for `predecessor` to finish before it begins.
+    ///
+    /// This must be called before the job is spawned.
+    pub(crate) fn set_predecessor(&self, predecessor: Monitor) {
+        self.report("Waiting for the previous job to finish");
+        let mut slot = self
+            .0
+            .predecessor
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner);
+        *slot = Some(predecessor);
+    }
+
+    /// Returns the job that must finish before this job begins, if any.
+    ///
+    /// The predecessor is kept until this job finishes, so that the chain of
+    /// queued jobs can be followed from the latest one.
+    pub(crate) fn predecessor(&self) -> Option<Monitor> {
+        self.0
+            .predecessor
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .clone()
+    }
+
+    /// Returns `true` if this monitor belongs to a job that has been spawned.
+    pub(crate) fn is_attached(&self) -> bool {
+        self.0.attached.load(Ordering::Acquire)
+    }
+
+    /// Creates the [`AbortRegistration`] that [`cancel`](Self::cancel) aborts.
+    ///
+    /// This must be called once, before the job is spawned.
+    pub(crate) fn abort_registration(&self) -> AbortRegistration {
+        let (abort_handle, registration) = AbortHandle::new_pair();
+        self.0.abort_handle.set(abort_handle).unwrap();
+        registration
+    }
+
+    /// Records that the job has been spawned.
+    pub(crate) fn set_spawned(&self) {
+        self.0.attached.store(true, Ordering::Release);
+        #[cfg(feature = "metrics")]
+        crate::metric::spawned();
+    }
+
+    /// Records that the job has begun running, after waiting for its
+    /// predecessor.
+    pub(crate) fn set_running(&self) {
+        self.0.began_running.store(true, Ordering::Release);
+        #[cfg(feature = "metrics")]
+        crate::metric::running();
+        self.notify(MonitorEvent::Running);
+    }
+
+    /// Returns `true` if the job has begun running, after waiting for its
+    /// predecessor.
+    #[cfg(feature = "metrics")]
+    pub(crate) fn began_running(&self) -> bool {
+        self.0.began_running.load(Ordering::Acquire)
+    }
+
     pub(crate) fn set_finished<T, E>(&self, result: Result<T, E>)
     where
         T: Into<JobReturnStatus>,
@@ -174,6 +510,183 @@ impl Monitor {
             is_success: return_status.is_success,
         };
         self.0.finished.set(finished_info).unwrap();
+        // Finished jobs don't need to wait for anything, and letting go of the
+        // predecessor keeps long chains of queued jobs from piling up.
+        self.0
+            .predecessor
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .take();
+
+        #[cfg(feature = "tracing")]
+        crate::trace::finished(self);
+        #[cfg(feature = "metrics")]
+        crate::metric::finished(self);
+
+        // Wake up everyone waiting for the job to finish
+        let waiters = std::mem::take(&mut self.lock_waiters().wakers);
+        for waiter in waiters.into_values() {
+            waiter.wake();
+        }
+
+        self.notify(MonitorEvent::Finished);
+
+        let hooks =
+            std::mem::take(&mut *self.0.hooks.lock().unwrap_or_else(PoisonError::into_inner));
+        for hook in hooks {
+            self.run_hook(hook);
+        }
+    }
+
+    fn block_until(&self, deadline: Option<Instant>) -> Result<()> {
+        assert!(
+            !runtime::is_in_job(),
+            "cannot block the thread while waiting for a job from inside a job",
+        );
+        assert!(
+            !runtime::is_in_async_context(),
+            "cannot block the thread while waiting for a job from inside an async runtime",
+        );
+
+        let waker = Waker::from(Arc::new(ThreadWaker(thread::current())));
+        let mut cx = Context::from_waker(&waker);
+        let mut finished = self.finished();
+        loop {
+            if let Poll::Ready(succeeded) = Pin::new(&mut finished).poll(&mut cx) {
+                return if succeeded {
+                    Ok(())
+                } else {
+                    Err(Error::JobFailed)
+                };
+            }
+            match deadline {
+                Some(deadline) => {
+                    let now = Instant::now();
+                    if now >= deadline {
+                        return Err(Error::Timeout);
+                    }
+                    thread::park_timeout(deadline - now);
+                }
+                None => thread::park(),
+            }
+        }
+    }
+
+    /// Returns the number of [`Finished`] futures waiting for the job.
+    #[cfg(all(test, any(feature = "tokio", feature = "smol")))]
+    pub(crate) fn waiter_count(&self) -> usize {
+        self.lock_waiters().wakers.len()
+    }
+
+    fn lock_waiters(&self) -> MutexGuard<'_, Waiters> {
+        // Wakers can't leave the list in an inconsistent state, so it's fine
+        // to ignore poisoning.
+        self.0
+            .waiters
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+/// Reports a status message to the [current job's
+/// monitor](Monitor::current), using the same syntax as [`format!`].
+///
+/// Outside of a job, this does nothing, so it can be used by helper functions
+/// that may or may not be running inside a job.
+///
+/// # Examples
+///
+/// ```
+/// # #[tokio::main]
+/// # async fn main() {
+/// use girlboss::report;
+/// use girlboss::tokio::Job;
+///
+/// fn import(rows: usize) {
+///     for row in 1..=rows {
+///         report!("Imported row {row} of {rows}");
+///     }
+/// }
+///
+/// import(3); // does nothing
+/// let job = Job::start(|_| async { import(5) });
+/// job.wait().await.unwrap();
+/// assert_eq!(job.status().message(), "Imported row 5 of 5");
+/// # }
+/// ```
+#[macro_export]
+macro_rules! report {
+    ($($arg:tt)*) => {
+        if let ::std::option::Option::Some(monitor) = $crate::Monitor::current() {
+            monitor.write_fmt(::std::format_args!($($arg)*));
+        }
+    };
+}
+
+/// A future that resolves once a job is finished, returned by
+/// [`Monitor::finished`].
+///
+/// The output is whether the job succeeded. Cloning this future is cheap.
+#[derive(Debug)]
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct Finished {
+    monitor: Monitor,
+    /// The key of this future's waker in the monitor, once it has one.
+    key: Option<u64>,
+}
+
+impl Finished {
+    /// Returns the monitor of the job that this future waits for.
+    pub(crate) fn monitor(&self) -> &Monitor {
+        &self.monitor
+    }
+}
+
+impl Future for Finished {
+    type Output = bool;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<bool> {
+        if let Some(outcome) = self.monitor.outcome() {
+            return Poll::Ready(outcome);
+        }
+
+        let this = &mut *self;
+        let mut waiters = this.monitor.lock_waiters();
+        // Check again while holding the lock, since the job may have finished
+        // in the meantime, in which case nobody would wake us up.
+        if let Some(outcome) = this.monitor.outcome() {
+            return Poll::Ready(outcome);
+        }
+        let key = *this.key.get_or_insert_with(|| {
+            waiters.next_key += 1;
+            waiters.next_key
+        });
+        match waiters.wakers.entry(key) {
+            Entry::Occupied(mut entry) => {
+                if !entry.get().will_wake(cx.waker()) {
+                    entry.insert(cx.waker().clone());
+                }
+            }
+            Entry::Vacant(entry) => {
+                entry.insert(cx.waker().clone());
+            }
+        }
+        Poll::Pending
+    }
+}
+
+impl Clone for Finished {
+    fn clone(&self) -> Self {
+        // The clone registers its own waker when it's polled.
+        self.monitor.finished()
+    }
+}
+
+impl Drop for Finished {
+    fn drop(&mut self) {
+        if let Some(key) = self.key {
+            self.monitor.lock_waiters().wakers.remove(&key);
+        }
     }
 }
 
@@ -45,6 +45,10 @@ impl JobReturnStatus {
     pub(crate) fn panicked() -> Self {
         JobReturnStatus::new(Some("The job panicked".into()), false)
     }
+
+    pub(crate) fn cancelled() -> Self {
+        JobReturnStatus::new(Some("The job was cancelled".into()), false)
+    }
 }
 
 impl Default for JobReturnStatus {
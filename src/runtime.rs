@@ -1,33 +1,88 @@
 //! Traits for interoperability between async runtimes.
+//!
+//! Runtimes that are not supported out of the box can be used by
+//! implementing [`Executor`] and using the [`Custom`] runtime.
 
+use std::cell::{Cell, RefCell};
 use std::future::Future;
+use std::panic::AssertUnwindSafe;
+use std::sync::Arc;
+use std::task::Wake;
+use std::time::Duration;
 
 #[cfg(feature = "actix-rt")]
 mod actix_rt;
+mod completion;
+mod custom;
+#[cfg(feature = "futures-executor")]
+mod futures_executor;
+#[cfg(any(feature = "tokio", feature = "actix-rt"))]
+mod join;
+#[cfg(feature = "smol")]
+mod smol;
+#[cfg(feature = "thread")]
+mod thread;
+mod timer;
 #[cfg(feature = "tokio")]
 mod tokio;
+#[cfg(feature = "tokio-local")]
+mod tokio_local;
 
 #[cfg(feature = "actix-rt")]
 pub use actix_rt::ActixRt;
+pub use custom::{Custom, Executor};
+use futures::future::{self, poll_fn, Abortable, Either, RemoteHandle};
+use futures::FutureExt;
+#[cfg(feature = "futures-executor")]
+pub use futures_executor::{LocalPool, ThreadPool};
 use sealed::sealed;
+#[cfg(feature = "smol")]
+pub use smol::Smol;
+#[cfg(feature = "thread")]
+pub use thread::Thread;
 #[cfg(feature = "tokio")]
 pub use tokio::Tokio;
+#[cfg(feature = "tokio-local")]
+pub use tokio_local::TokioLocal;
 
-use crate::Monitor;
+use crate::{Error, JobReturnStatus, Monitor, Result};
 
 /// An async runtime.
 #[sealed]
-pub trait Runtime: Sized {
+pub trait Runtime: Sized + 'static {
     /// The [`JobHandle`] used by this runtime.
     type JobHandle: JobHandle<Self>;
+
+    /// The spawner that a job manager can be configured to start its jobs on,
+    /// or [`NoSpawner`] if this runtime doesn't have one.
+    type Spawner: Clone + Send + Sync + 'static;
+
+    /// Waits until `duration` has elapsed, using this runtime's timer.
+    fn sleep(duration: Duration) -> impl Future<Output = ()> + Send + 'static;
+
+    /// Runs `func` on a thread where blocking is acceptable, and returns a
+    /// [`JobHandle`] for it.
+    fn spawn_blocking<F>(func: F) -> Self::JobHandle
+    where
+        F: FnOnce() + Send + 'static;
+
+    /// Like [`spawn_blocking`](Self::spawn_blocking), but uses `spawner`.
+    fn spawn_blocking_on<F>(spawner: &Self::Spawner, func: F) -> Self::JobHandle
+    where
+        F: FnOnce() + Send + 'static;
 }
 
+/// The [`Runtime::Spawner`] of runtimes that can't be configured with a
+/// spawner. This type has no values.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum NoSpawner {}
+
 /// A job handle in the runtime `R`, roughly analogous to a mutex-wrapped
 /// `JoinHandle`.
 #[sealed]
-pub trait JobHandle<R: Runtime>: 'static {
+pub trait JobHandle<R: Runtime>: Send + Sync + 'static {
     /// Waits for the job to finish.
-    fn wait(&self) -> impl std::future::Future<Output = ()>;
+    fn wait(&self) -> impl std::future::Future<Output = ()> + Send;
 }
 
 /// A future that can be spawned using the runtime `R`.
@@ -35,4 +90,231 @@ pub trait JobHandle<R: Runtime>: 'static {
 pub trait Spawnable<R: Runtime>: Future + 'static {
     /// Spawns the future and returns a [`JobHandle`].
     fn spawn(self, monitor: Monitor) -> R::JobHandle;
+
+    /// Spawns the future using `spawner` and returns a [`JobHandle`].
+    fn spawn_on(self, spawner: &R::Spawner, monitor: Monitor) -> R::JobHandle;
+}
+
+/// A handle to a task that was spawned outside of this crate, which can be
+/// tracked by a job in the runtime `R` using
+/// [`Job::adopt`](crate::common::Job::adopt).
+///
+/// This is implemented for the join handles of the supported runtimes, such
+/// as Tokio's `JoinHandle` and smol's `Task`, as well as for
+/// [`RemoteHandle`], which works with any runtime.
+#[sealed]
+pub trait Adoptable<R: Runtime>: 'static {
+    /// Spawns a job that waits for the task, and returns its [`JobHandle`].
+    fn adopt(self, monitor: Monitor) -> R::JobHandle;
+}
+
+#[sealed]
+impl<R, T> Adoptable<R> for RemoteHandle<T>
+where
+    R: Runtime,
+    T: Into<JobReturnStatus> + Send + 'static,
+    RemoteHandle<T>: Spawnable<R>,
+{
+    fn adopt(self, monitor: Monitor) -> R::JobHandle {
+        // Dropping the handle cancels the task, and awaiting it resumes the
+        // task's panic, so it can be spawned like any other job.
+        self.spawn(monitor)
+    }
+}
+
+/// Something that the future `F` can be spawned on in the runtime `R`, such
+/// as a Tokio [`Handle`] or an actix-rt [`ArbiterHandle`]. Used by
+/// [`Job::start_on`](crate::common::Job::start_on).
+///
+/// [`Handle`]: https://docs.rs/tokio/1/tokio/runtime/struct.Handle.html
+/// [`ArbiterHandle`]: https://docs.rs/actix-rt/2/actix_rt/struct.ArbiterHandle.html
+#[sealed]
+pub trait SpawnOn<R: Runtime, F: Future> {
+    /// Spawns the future and returns a [`JobHandle`].
+    fn spawn_on(&self, future: F, monitor: Monitor) -> R::JobHandle;
+}
+
+/// Wraps a job future into the future that actually gets spawned, which
+/// handles waiting for the job's predecessor, cancellation, panics, and
+/// recording the result in the monitor.
+fn wrap<F>(future: F, monitor: Monitor) -> impl Future<Output = ()>
+where
+    F: Future,
+    F::Output: Into<JobReturnStatus>,
+{
+    let registration = monitor.abort_registration();
+    monitor.set_spawned();
+    async move {
+        let predecessor = monitor.predecessor();
+        let job = async {
+            if let Some(predecessor) = &predecessor {
+                predecessor.finished().await;
+            }
+            monitor.set_running();
+            AssertUnwindSafe(future).catch_unwind().await
+        };
+        let mut job = std::pin::pin!(Abortable::new(job, registration));
+        let job = poll_fn(|cx| {
+            let _guard = JobGuard::enter();
+            let _current = CurrentMonitorGuard::enter(&monitor);
+            #[cfg(feature = "tracing")]
+            let _span = monitor.span().enter();
+            job.as_mut().poll(cx)
+        });
+        if let Ok(result) = job.await {
+            monitor.set_finished(result);
+            return;
+        }
+
+        // A cancelled job still only finishes after its predecessor, so that
+        // the jobs queued after it never run alongside it.
+        if let Some(predecessor) = &predecessor {
+            predecessor.finished().await;
+        }
+        monitor.set_finished(Ok::<_, ()>(JobReturnStatus::cancelled()));
+    }
+}
+
+/// Like [`wrap`], but for a blocking job function, which is called with the
+/// job's monitor on the thread that runs it.
+fn wrap_blocking<F, T>(func: F, monitor: Monitor) -> impl FnOnce() + Send + 'static
+where
+    F: FnOnce(Monitor) -> T + Send + 'static,
+    T: Into<JobReturnStatus>,
+{
+    monitor.set_spawned();
+    move || {
+        if let Some(predecessor) = monitor.predecessor() {
+            let _ = predecessor.wait_blocking();
+        }
+        monitor.set_running();
+        let result = std::panic::catch_unwind(AssertUnwindSafe(|| {
+            let _current = CurrentMonitorGuard::enter(&monitor);
+            #[cfg(feature = "tracing")]
+            let _span = monitor.span().enter();
+            func(monitor.clone())
+        }));
+        monitor.set_finished(result);
+    }
+}
+
+/// Spawns a blocking job function using the runtime `R`.
+pub(crate) fn spawn_blocking<R, F, T>(func: F, monitor: Monitor) -> R::JobHandle
+where
+    R: Runtime,
+    F: FnOnce(Monitor) -> T + Send + 'static,
+    T: Into<JobReturnStatus>,
+{
+    R::spawn_blocking(wrap_blocking(func, monitor))
+}
+
+/// Spawns a blocking job function using `spawner`.
+pub(crate) fn spawn_blocking_on<R, F, T>(
+    spawner: &R::Spawner,
+    func: F,
+    monitor: Monitor,
+) -> R::JobHandle
+where
+    R: Runtime,
+    F: FnOnce(Monitor) -> T + Send + 'static,
+    T: Into<JobReturnStatus>,
+{
+    R::spawn_blocking_on(spawner, wrap_blocking(func, monitor))
+}
+
+thread_local! {
+    /// How many jobs are being polled on this thread right now.
+    static JOB_DEPTH: Cell<usize> = const { Cell::new(0) };
+}
+
+/// Marks the current thread as polling a job, until dropped.
+struct JobGuard;
+
+impl JobGuard {
+    fn enter() -> JobGuard {
+        JOB_DEPTH.set(JOB_DEPTH.get() + 1);
+        JobGuard
+    }
+}
+
+impl Drop for JobGuard {
+    fn drop(&mut self) {
+        JOB_DEPTH.set(JOB_DEPTH.get() - 1);
+    }
+}
+
+/// Returns `true` if this is called from inside a job.
+pub(crate) fn is_in_job() -> bool {
+    JOB_DEPTH.get() > 0
+}
+
+/// Returns `true` if this thread is driving an async runtime, as far as we
+/// can tell, in which case blocking it could stall the runtime forever.
+pub(crate) fn is_in_async_context() -> bool {
+    // A thread that has a current Tokio runtime but isn't running one of its
+    // tasks is driving the runtime, e.g. in the body of `#[tokio::main]`.
+    // Tasks can't be told apart from blocking functions this way, since both
+    // have a task ID, so only the former are detected.
+    #[cfg(feature = "tokio")]
+    if ::tokio::runtime::Handle::try_current().is_ok() && ::tokio::task::try_id().is_none() {
+        return true;
+    }
+    #[cfg(feature = "actix-rt")]
+    if ::actix_rt::Arbiter::try_current().is_some() {
+        return true;
+    }
+    false
+}
+
+thread_local! {
+    /// The monitor of the job that is running on this thread right now.
+    static CURRENT_MONITOR: RefCell<Option<Monitor>> = const { RefCell::new(None) };
+}
+
+/// Makes a job's monitor the [current monitor](current_monitor), until
+/// dropped, after which the previous one is restored.
+struct CurrentMonitorGuard {
+    previous: Option<Monitor>,
+}
+
+impl CurrentMonitorGuard {
+    fn enter(monitor: &Monitor) -> CurrentMonitorGuard {
+        let previous = CURRENT_MONITOR.replace(Some(monitor.clone()));
+        CurrentMonitorGuard { previous }
+    }
+}
+
+impl Drop for CurrentMonitorGuard {
+    fn drop(&mut self) {
+        CURRENT_MONITOR.set(self.previous.take());
+    }
+}
+
+/// Returns the monitor of the job that is running on this thread, if any.
+pub(crate) fn current_monitor() -> Option<Monitor> {
+    CURRENT_MONITOR.with_borrow(Option::clone)
+}
+
+/// Wakes up a thread that parked itself while waiting for a future.
+pub(crate) struct ThreadWaker(pub(crate) std::thread::Thread);
+
+impl Wake for ThreadWaker {
+    fn wake(self: Arc<Self>) {
+        self.0.unpark();
+    }
+}
+
+/// Runs `future` to completion, unless `duration` elapses first, in which case
+/// this returns <code>Err([Error::Timeout])</code>.
+pub(crate) async fn timeout<R, F>(duration: Duration, future: F) -> Result<F::Output>
+where
+    R: Runtime,
+    F: Future,
+{
+    let future = std::pin::pin!(future);
+    let sleep = std::pin::pin!(R::sleep(duration));
+    match future::select(future, sleep).await {
+        Either::Left((output, _)) => Ok(output),
+        Either::Right(((), _)) => Err(Error::Timeout),
+    }
 }
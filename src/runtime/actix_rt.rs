@@ -1,29 +1,72 @@
-use std::cell::RefCell;
 use std::future::Future;
-use std::panic::AssertUnwindSafe;
+use std::sync::{Arc, Mutex, PoisonError};
+use std::time::Duration;
 
 use actix_rt::task::JoinHandle;
-use futures::FutureExt;
+use actix_rt::ArbiterHandle;
 use sealed::sealed;
 
+use super::completion::CompletionHandle;
+use super::join::join;
 use crate::{JobReturnStatus, Monitor};
 
 /// Represents the actix-rt async runtime.
+///
+/// Jobs are spawned onto the current arbiter, unless they are started using
+/// [`Job::start_on`](crate::common::Job::start_on) with an [`ArbiterHandle`],
+/// in which case they must be `Send`.
+///
+/// A manager can also be given an [`ArbiterHandle`] as its spawner, which is
+/// only used for blocking jobs: they are then run by that arbiter's runtime.
+/// Async jobs may not be `Send`, so they can't be moved to another arbiter's
+/// thread, and are still spawned onto the current arbiter.
 pub enum ActixRt {}
 
-pub struct ActixRtHandle(RefCell<Option<JoinHandle<()>>>);
-
 #[sealed]
 impl super::Runtime for ActixRt {
-    type JobHandle = ActixRtHandle;
-}
+    type JobHandle = CompletionHandle;
+    type Spawner = ArbiterHandle;
 
-#[sealed]
-impl super::JobHandle<ActixRt> for ActixRtHandle {
-    async fn wait(&self) {
-        if let Some(handle) = &mut *self.0.borrow_mut() {
-            let _ = handle.await;
+    fn sleep(duration: Duration) -> impl Future<Output = ()> + Send + 'static {
+        actix_rt::time::sleep(duration)
+    }
+
+    fn spawn_blocking<F>(func: F) -> CompletionHandle
+    where
+        F: FnOnce() + Send + 'static,
+    {
+        let (sender, handle) = CompletionHandle::new();
+        actix_rt::task::spawn_blocking(move || {
+            func();
+            let _ = sender.send(());
+        });
+        handle
+    }
+
+    fn spawn_blocking_on<F>(spawner: &ArbiterHandle, func: F) -> CompletionHandle
+    where
+        F: FnOnce() + Send + 'static,
+    {
+        // If the arbiter isn't running, the closure passed to it is dropped,
+        // so keep `func` where it can be taken back and run here instead.
+        // Otherwise the job's monitor would never be finished.
+        let func = Arc::new(Mutex::new(Some(func)));
+        let (sender, handle) = CompletionHandle::new();
+        let remote_func = func.clone();
+        let is_spawned = spawner.spawn_fn(move || {
+            actix_rt::task::spawn_blocking(move || {
+                if let Some(func) = take(&remote_func) {
+                    func();
+                }
+                let _ = sender.send(());
+            });
+        });
+        if !is_spawned {
+            if let Some(func) = take(&func) {
+                return Self::spawn_blocking(func);
+            }
         }
+        handle
     }
 }
 
@@ -33,11 +76,55 @@ where
     F: Future + 'static,
     F::Output: Into<JobReturnStatus>,
 {
-    fn spawn(self, monitor: Monitor) -> ActixRtHandle {
-        let handle = actix_rt::spawn(async move {
-            let result = AssertUnwindSafe(self).catch_unwind().await;
-            monitor.set_finished(result);
+    fn spawn(self, monitor: Monitor) -> CompletionHandle {
+        let (sender, handle) = CompletionHandle::new();
+        let future = super::wrap(self, monitor);
+        actix_rt::spawn(async move {
+            future.await;
+            let _ = sender.send(());
+        });
+        handle
+    }
+
+    fn spawn_on(self, _spawner: &ArbiterHandle, monitor: Monitor) -> CompletionHandle {
+        // The future may not be `Send`, so it can't be moved to the arbiter's
+        // thread. Only blocking jobs use the spawner.
+        super::Spawnable::<ActixRt>::spawn(self, monitor)
+    }
+}
+
+#[sealed]
+impl<T> super::Adoptable<ActixRt> for JoinHandle<T>
+where
+    T: Into<JobReturnStatus> + 'static,
+{
+    fn adopt(self, monitor: Monitor) -> CompletionHandle {
+        super::Spawnable::<ActixRt>::spawn(join(self), monitor)
+    }
+}
+
+/// Takes the value out of a shared slot.
+fn take<T>(slot: &Mutex<Option<T>>) -> Option<T> {
+    slot.lock().unwrap_or_else(PoisonError::into_inner).take()
+}
+
+#[sealed]
+impl<F> super::SpawnOn<ActixRt, F> for ArbiterHandle
+where
+    F: Future + Send + 'static,
+    F::Output: Into<JobReturnStatus>,
+{
+    fn spawn_on(&self, future: F, monitor: Monitor) -> CompletionHandle {
+        let (sender, handle) = CompletionHandle::new();
+        let future = super::wrap(future, monitor.clone());
+        let is_spawned = self.spawn(async move {
+            future.await;
+            let _ = sender.send(());
         });
-        ActixRtHandle(RefCell::new(Some(handle)))
+        if !is_spawned {
+            let status = JobReturnStatus::new(Some("The arbiter is not running".into()), false);
+            monitor.set_finished(Ok::<_, ()>(status));
+        }
+        handle
     }
 }
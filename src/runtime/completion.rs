@@ -0,0 +1,44 @@
+#[cfg(any(feature = "futures-executor", feature = "thread"))]
+use std::thread;
+
+use futures::channel::oneshot;
+use futures::future::Shared;
+use futures::FutureExt;
+use sealed::sealed;
+
+/// A [`JobHandle`](super::JobHandle) for runtimes whose tasks can't be joined from several
+/// places at once, or at all. The task sends a message once it is done.
+pub struct CompletionHandle(Shared<oneshot::Receiver<()>>);
+
+impl CompletionHandle {
+    /// Creates a handle, along with the sender that the task should use to
+    /// signal that it is done.
+    pub(super) fn new() -> (oneshot::Sender<()>, CompletionHandle) {
+        let (sender, receiver) = oneshot::channel();
+        (sender, CompletionHandle(receiver.shared()))
+    }
+}
+
+#[sealed]
+impl<R: super::Runtime> super::JobHandle<R> for CompletionHandle {
+    async fn wait(&self) {
+        // If the task died without sending anything, the receiver gets
+        // cancelled, which is fine too.
+        let _ = self.0.clone().await;
+    }
+}
+
+/// Spawns a thread to run a job, returning a handle that completes once the
+/// thread is done.
+#[cfg(any(feature = "futures-executor", feature = "thread"))]
+pub(super) fn spawn_thread(func: impl FnOnce() + Send + 'static) -> CompletionHandle {
+    let (sender, handle) = CompletionHandle::new();
+    thread::Builder::new()
+        .name("girlboss-job".into())
+        .spawn(move || {
+            func();
+            let _ = sender.send(());
+        })
+        .expect("failed to spawn a job thread");
+    handle
+}
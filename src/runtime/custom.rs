@@ -0,0 +1,131 @@
+use std::future::Future;
+use std::marker::PhantomData;
+use std::time::Duration;
+
+use futures::future::BoxFuture;
+use futures::FutureExt;
+use sealed::sealed;
+
+use super::completion::CompletionHandle;
+use super::timer;
+use super::NoSpawner;
+use crate::{JobReturnStatus, Monitor};
+
+/// An executor that can be plugged into this crate using [`Custom`].
+///
+/// Only [`spawn`](Self::spawn) needs to be implemented. Job functions,
+/// panic catching, cancellation and reporting the result to the job's
+/// [`Monitor`] are all handled by this crate; the executor just needs to poll
+/// the futures that it is given.
+///
+/// # Contract
+///
+/// * [`spawn`](Self::spawn) must poll the future to completion, and it must
+///   not wait for the future to finish before returning.
+/// * The spawned futures never panic, since this crate catches panics inside
+///   them.
+/// * [`spawn_blocking`](Self::spawn_blocking) must run the function exactly
+///   once, somewhere where blocking the thread is acceptable.
+/// * The future returned by [`sleep`](Self::sleep) must resolve once
+///   `duration` has elapsed, but not much later.
+///
+/// # Examples
+///
+/// An executor that runs each job on its own thread:
+///
+/// ```
+/// use futures::future::BoxFuture;
+/// use girlboss::common::Job;
+/// use girlboss::runtime::{Custom, Executor};
+///
+/// enum MyExecutor {}
+///
+/// impl Executor for MyExecutor {
+///     fn spawn(future: BoxFuture<'static, ()>) {
+///         std::thread::spawn(move || futures::executor::block_on(future));
+///     }
+/// }
+///
+/// let job = Job::<Custom<MyExecutor>>::start(|mon| async move {
+///     write!(mon, "Hello from my executor");
+/// });
+/// job.wait_blocking().unwrap();
+/// assert_eq!(job.status().message(), "Hello from my executor");
+/// ```
+pub trait Executor: 'static {
+    /// Spawns a future that will be polled to completion in the background.
+    fn spawn(future: BoxFuture<'static, ()>);
+
+    /// Runs a function that may block the thread in the background.
+    ///
+    /// By default, this runs the function on a new thread.
+    fn spawn_blocking(func: Box<dyn FnOnce() + Send>) {
+        std::thread::spawn(func);
+    }
+
+    /// Returns a future that resolves after `duration`.
+    ///
+    /// By default, this uses a timer that runs on a background thread.
+    fn sleep(duration: Duration) -> impl Future<Output = ()> + Send + 'static {
+        timer::sleep(duration)
+    }
+}
+
+/// Represents a runtime built from a user-supplied [`Executor`].
+///
+/// Jobs must be `Send` to be spawned onto a custom executor.
+pub struct Custom<E: Executor>(PhantomData<fn() -> E>);
+
+#[sealed]
+impl<E: Executor> super::Runtime for Custom<E> {
+    type JobHandle = CompletionHandle;
+    type Spawner = NoSpawner;
+
+    fn sleep(duration: Duration) -> impl Future<Output = ()> + Send + 'static {
+        E::sleep(duration)
+    }
+
+    fn spawn_blocking<F>(func: F) -> CompletionHandle
+    where
+        F: FnOnce() + Send + 'static,
+    {
+        let (sender, handle) = CompletionHandle::new();
+        E::spawn_blocking(Box::new(move || {
+            func();
+            let _ = sender.send(());
+        }));
+        handle
+    }
+
+    fn spawn_blocking_on<F>(spawner: &NoSpawner, _func: F) -> CompletionHandle
+    where
+        F: FnOnce() + Send + 'static,
+    {
+        match *spawner {}
+    }
+}
+
+#[sealed]
+impl<F, E> super::Spawnable<Custom<E>> for F
+where
+    F: Future + Send + 'static,
+    F::Output: Into<JobReturnStatus>,
+    E: Executor,
+{
+    fn spawn(self, monitor: Monitor) -> CompletionHandle {
+        let (sender, handle) = CompletionHandle::new();
+        let future = super::wrap(self, monitor);
+        E::spawn(
+            async move {
+                future.await;
+                let _ = sender.send(());
+            }
+            .boxed(),
+        );
+        handle
+    }
+
+    fn spawn_on(self, spawner: &NoSpawner, _monitor: Monitor) -> CompletionHandle {
+        match *spawner {}
+    }
+}
@@ -0,0 +1,190 @@
+use std::cell::RefCell;
+use std::future::Future;
+use std::sync::OnceLock;
+use std::time::Duration;
+
+use futures::executor;
+use futures::task::{LocalSpawnExt, SpawnExt};
+use sealed::sealed;
+
+use super::completion::{spawn_thread, CompletionHandle};
+use super::timer;
+use super::NoSpawner;
+use crate::{JobReturnStatus, Monitor};
+
+/// Represents a global [`futures::executor::ThreadPool`].
+///
+/// The thread pool is created the first time a job is started. Blocking jobs
+/// run on their own threads instead, so that they don't tie up the pool.
+pub enum ThreadPool {}
+
+/// Represents a [`futures::executor::LocalPool`] that belongs to the current
+/// thread.
+///
+/// Jobs may be non-`Send`, and they only make progress while the current
+/// thread runs the pool, using [`run`](LocalPool::run),
+/// [`run_until`](LocalPool::run_until) or
+/// [`run_until_stalled`](LocalPool::run_until_stalled). This makes it useful
+/// for deterministic tests. Blocking jobs run on their own threads instead.
+///
+/// # Examples
+///
+/// ```
+/// use girlboss::local_pool::Job;
+/// use girlboss::runtime::LocalPool;
+///
+/// let job = Job::start(|mon| async move {
+///     write!(mon, "Done");
+/// });
+/// assert!(!job.is_finished());
+///
+/// LocalPool::run_until_stalled();
+/// assert_eq!(job.status().message(), "Done");
+/// ```
+pub enum LocalPool {}
+
+impl ThreadPool {
+    fn get() -> &'static executor::ThreadPool {
+        static POOL: OnceLock<executor::ThreadPool> = OnceLock::new();
+        POOL.get_or_init(|| {
+            executor::ThreadPool::builder()
+                .name_prefix("girlboss-pool-")
+                .create()
+                .expect("failed to create the thread pool")
+        })
+    }
+}
+
+thread_local! {
+    static LOCAL_POOL: RefCell<executor::LocalPool> = RefCell::new(executor::LocalPool::new());
+    static LOCAL_SPAWNER: executor::LocalSpawner =
+        LOCAL_POOL.with_borrow(executor::LocalPool::spawner);
+}
+
+impl LocalPool {
+    /// Runs this thread's pool until all of its jobs are finished.
+    ///
+    /// # Panics
+    ///
+    /// Panics if this thread's pool is already running, for example if this
+    /// is called from inside a job.
+    pub fn run() {
+        LOCAL_POOL.with_borrow_mut(executor::LocalPool::run)
+    }
+
+    /// Runs this thread's pool until `future` is finished, and returns the
+    /// future's output.
+    ///
+    /// # Panics
+    ///
+    /// Panics if this thread's pool is already running.
+    pub fn run_until<F: Future>(future: F) -> F::Output {
+        LOCAL_POOL.with_borrow_mut(|pool| pool.run_until(future))
+    }
+
+    /// Runs this thread's pool until none of its jobs can make progress, then
+    /// returns.
+    ///
+    /// # Panics
+    ///
+    /// Panics if this thread's pool is already running.
+    pub fn run_until_stalled() {
+        LOCAL_POOL.with_borrow_mut(executor::LocalPool::run_until_stalled)
+    }
+}
+
+#[sealed]
+impl super::Runtime for ThreadPool {
+    type JobHandle = CompletionHandle;
+    type Spawner = NoSpawner;
+
+    fn sleep(duration: Duration) -> impl Future<Output = ()> + Send + 'static {
+        timer::sleep(duration)
+    }
+
+    fn spawn_blocking<F>(func: F) -> CompletionHandle
+    where
+        F: FnOnce() + Send + 'static,
+    {
+        spawn_thread(func)
+    }
+
+    fn spawn_blocking_on<F>(spawner: &NoSpawner, _func: F) -> CompletionHandle
+    where
+        F: FnOnce() + Send + 'static,
+    {
+        match *spawner {}
+    }
+}
+
+#[sealed]
+impl super::Runtime for LocalPool {
+    type JobHandle = CompletionHandle;
+    type Spawner = NoSpawner;
+
+    fn sleep(duration: Duration) -> impl Future<Output = ()> + Send + 'static {
+        timer::sleep(duration)
+    }
+
+    fn spawn_blocking<F>(func: F) -> CompletionHandle
+    where
+        F: FnOnce() + Send + 'static,
+    {
+        spawn_thread(func)
+    }
+
+    fn spawn_blocking_on<F>(spawner: &NoSpawner, _func: F) -> CompletionHandle
+    where
+        F: FnOnce() + Send + 'static,
+    {
+        match *spawner {}
+    }
+}
+
+#[sealed]
+impl<F> super::Spawnable<ThreadPool> for F
+where
+    F: Future + Send + 'static,
+    F::Output: Into<JobReturnStatus>,
+{
+    fn spawn(self, monitor: Monitor) -> CompletionHandle {
+        let (sender, handle) = CompletionHandle::new();
+        let future = super::wrap(self, monitor);
+        ThreadPool::get()
+            .spawn(async move {
+                future.await;
+                let _ = sender.send(());
+            })
+            .expect("failed to spawn a job onto the thread pool");
+        handle
+    }
+
+    fn spawn_on(self, spawner: &NoSpawner, _monitor: Monitor) -> CompletionHandle {
+        match *spawner {}
+    }
+}
+
+#[sealed]
+impl<F> super::Spawnable<LocalPool> for F
+where
+    F: Future + 'static,
+    F::Output: Into<JobReturnStatus>,
+{
+    fn spawn(self, monitor: Monitor) -> CompletionHandle {
+        let (sender, handle) = CompletionHandle::new();
+        let future = super::wrap(self, monitor);
+        LOCAL_SPAWNER
+            .with(|spawner| {
+                spawner.spawn_local(async move {
+                    future.await;
+                    let _ = sender.send(());
+                })
+            })
+            .expect("failed to spawn a job onto the local pool");
+        handle
+    }
+
+    fn spawn_on(self, spawner: &NoSpawner, _monitor: Monitor) -> CompletionHandle {
+        match *spawner {}
+    }
+}
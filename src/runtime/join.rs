@@ -0,0 +1,42 @@
+//! Joining tasks that were spawned on a Tokio runtime, which actix-rt uses
+//! too.
+
+use std::future::Future;
+
+#[cfg(not(feature = "tokio"))]
+use actix_rt::task::JoinHandle;
+#[cfg(feature = "tokio")]
+use tokio::task::JoinHandle;
+
+use crate::JobReturnStatus;
+
+/// Waits for a task that was spawned elsewhere. If the task panicked, its
+/// panic is resumed so that the job's monitor records it.
+///
+/// The task is aborted if this future is dropped first, which happens when
+/// the job is cancelled.
+pub(super) fn join<T>(handle: JoinHandle<T>) -> impl Future<Output = JobReturnStatus>
+where
+    T: Into<JobReturnStatus>,
+{
+    struct AbortOnDrop<T>(JoinHandle<T>);
+
+    impl<T> Drop for AbortOnDrop<T> {
+        fn drop(&mut self) {
+            self.0.abort();
+        }
+    }
+
+    // Wrap the handle right away, since the job may be cancelled before this
+    // future is ever polled.
+    let mut handle = AbortOnDrop(handle);
+    async move {
+        match (&mut handle.0).await {
+            Ok(output) => output.into(),
+            Err(error) => match error.try_into_panic() {
+                Ok(payload) => std::panic::resume_unwind(payload),
+                Err(_cancelled) => JobReturnStatus::cancelled(),
+            },
+        }
+    }
+}
@@ -0,0 +1,78 @@
+use std::future::Future;
+use std::time::Duration;
+
+use futures::FutureExt;
+use sealed::sealed;
+
+use super::completion::CompletionHandle;
+use super::NoSpawner;
+use crate::{JobReturnStatus, Monitor};
+
+/// Represents the smol async runtime.
+///
+/// Jobs are spawned onto smol's global executor, and blocking jobs are run
+/// with [`smol::unblock`].
+pub enum Smol {}
+
+#[sealed]
+impl super::Runtime for Smol {
+    type JobHandle = CompletionHandle;
+    type Spawner = NoSpawner;
+
+    fn sleep(duration: Duration) -> impl Future<Output = ()> + Send + 'static {
+        smol::Timer::after(duration).map(|_| ())
+    }
+
+    fn spawn_blocking<F>(func: F) -> CompletionHandle
+    where
+        F: FnOnce() + Send + 'static,
+    {
+        spawn_detached(smol::unblock(func))
+    }
+
+    fn spawn_blocking_on<F>(spawner: &NoSpawner, _func: F) -> CompletionHandle
+    where
+        F: FnOnce() + Send + 'static,
+    {
+        match *spawner {}
+    }
+}
+
+#[sealed]
+impl<F> super::Spawnable<Smol> for F
+where
+    F: Future + Send + 'static,
+    F::Output: Into<JobReturnStatus>,
+{
+    fn spawn(self, monitor: Monitor) -> CompletionHandle {
+        spawn_detached(super::wrap(self, monitor))
+    }
+
+    fn spawn_on(self, spawner: &NoSpawner, _monitor: Monitor) -> CompletionHandle {
+        match *spawner {}
+    }
+}
+
+#[sealed]
+impl<T> super::Adoptable<Smol> for smol::Task<T>
+where
+    T: Into<JobReturnStatus> + Send + 'static,
+{
+    fn adopt(self, monitor: Monitor) -> CompletionHandle {
+        // Dropping a task cancels it, and awaiting it resumes its panic.
+        super::Spawnable::<Smol>::spawn(self, monitor)
+    }
+}
+
+/// Spawns a task onto the global executor. Dropping a smol task cancels it,
+/// so the task is detached and signals its completion through the handle
+/// instead.
+fn spawn_detached(future: impl Future<Output = ()> + Send + 'static) -> CompletionHandle {
+    let (sender, handle) = CompletionHandle::new();
+    smol::spawn(async move {
+        future.await;
+        let _ = sender.send(());
+    })
+    .detach();
+    handle
+}
@@ -0,0 +1,94 @@
+use std::future::Future;
+use std::pin::pin;
+use std::sync::Arc;
+use std::task::{Context, Poll, Waker};
+use std::thread;
+use std::time::Duration;
+
+use sealed::sealed;
+
+use super::completion::{spawn_thread, CompletionHandle};
+use super::NoSpawner;
+use super::{timer, ThreadWaker};
+use crate::{JobReturnStatus, Monitor};
+
+/// Represents a runtime that runs each job on its own OS thread.
+///
+/// This does not need any async runtime. Each job's future is driven by a
+/// minimal executor on a dedicated thread, and timers are driven by a shared
+/// background thread. Blocking jobs simply run on their own thread too.
+///
+/// To wait for jobs from synchronous code, use
+/// [`Job::wait_blocking`](crate::common::Job::wait_blocking), or run async
+/// code such as [`Job::wait`](crate::common::Job::wait) on the current thread
+/// with [`Thread::block_on`].
+pub enum Thread {}
+
+impl Thread {
+    /// Runs a future to completion on the current thread, blocking it until
+    /// the future is finished.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use girlboss::runtime::Thread;
+    /// use girlboss::thread::Job;
+    ///
+    /// let job = Job::start(|mon| async move {
+    ///     write!(mon, "No async runtime required");
+    /// });
+    /// Thread::block_on(job.wait()).unwrap();
+    /// assert_eq!(job.status().message(), "No async runtime required");
+    /// ```
+    pub fn block_on<F: Future>(future: F) -> F::Output {
+        let waker = Waker::from(Arc::new(ThreadWaker(thread::current())));
+        let mut cx = Context::from_waker(&waker);
+        let mut future = pin!(future);
+        loop {
+            if let Poll::Ready(output) = future.as_mut().poll(&mut cx) {
+                return output;
+            }
+            thread::park();
+        }
+    }
+}
+
+#[sealed]
+impl super::Runtime for Thread {
+    type JobHandle = CompletionHandle;
+    type Spawner = NoSpawner;
+
+    fn sleep(duration: Duration) -> impl Future<Output = ()> + Send + 'static {
+        timer::sleep(duration)
+    }
+
+    fn spawn_blocking<F>(func: F) -> CompletionHandle
+    where
+        F: FnOnce() + Send + 'static,
+    {
+        spawn_thread(func)
+    }
+
+    fn spawn_blocking_on<F>(spawner: &NoSpawner, _func: F) -> CompletionHandle
+    where
+        F: FnOnce() + Send + 'static,
+    {
+        match *spawner {}
+    }
+}
+
+#[sealed]
+impl<F> super::Spawnable<Thread> for F
+where
+    F: Future + Send + 'static,
+    F::Output: Into<JobReturnStatus>,
+{
+    fn spawn(self, monitor: Monitor) -> CompletionHandle {
+        let future = super::wrap(self, monitor);
+        spawn_thread(move || Thread::block_on(future))
+    }
+
+    fn spawn_on(self, spawner: &NoSpawner, _monitor: Monitor) -> CompletionHandle {
+        match *spawner {}
+    }
+}
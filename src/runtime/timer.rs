@@ -0,0 +1,141 @@
+//! A timer for runtimes that don't come with one.
+
+use std::collections::btree_map::Entry;
+use std::collections::BTreeMap;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Condvar, Mutex, OnceLock, PoisonError};
+use std::task::{Context, Poll, Waker};
+use std::thread;
+use std::time::{Duration, Instant};
+
+/// A future that resolves once its deadline has passed.
+pub(crate) struct Sleep {
+    deadline: Instant,
+    /// The ID of this future's entry in the timer, once it has one.
+    id: Option<u64>,
+}
+
+/// Returns a future that resolves after `duration`.
+pub(crate) fn sleep(duration: Duration) -> Sleep {
+    Sleep {
+        deadline: Instant::now() + duration,
+        id: None,
+    }
+}
+
+impl Future for Sleep {
+    type Output = ();
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+        if Instant::now() >= self.deadline {
+            return Poll::Ready(());
+        }
+        let id = *self.id.get_or_insert_with(Timer::next_id);
+        Timer::get().register((self.deadline, id), cx.waker());
+        Poll::Pending
+    }
+}
+
+impl Drop for Sleep {
+    fn drop(&mut self) {
+        if let Some(id) = self.id {
+            Timer::get().remove((self.deadline, id));
+        }
+    }
+}
+
+/// The key of an entry in the timer: its deadline, and the ID of the [`Sleep`]
+/// that registered it.
+type Key = (Instant, u64);
+
+/// A background thread that wakes up sleeping futures.
+struct Timer {
+    /// The wakers to wake up, ordered by when to wake them up.
+    entries: Mutex<BTreeMap<Key, Waker>>,
+    /// Notified when an entry is added.
+    added: Condvar,
+}
+
+impl Timer {
+    /// Returns the global timer, starting its thread if needed.
+    fn get() -> &'static Timer {
+        static TIMER: OnceLock<Timer> = OnceLock::new();
+        static THREAD: OnceLock<()> = OnceLock::new();
+
+        let timer = TIMER.get_or_init(|| Timer {
+            entries: Mutex::new(BTreeMap::new()),
+            added: Condvar::new(),
+        });
+        THREAD.get_or_init(|| {
+            thread::Builder::new()
+                .name("girlboss-timer".into())
+                .spawn(|| timer.run())
+                .expect("failed to spawn the timer thread");
+        });
+        timer
+    }
+
+    /// Returns a new ID for a [`Sleep`].
+    fn next_id() -> u64 {
+        static NEXT_ID: AtomicU64 = AtomicU64::new(0);
+        NEXT_ID.fetch_add(1, Ordering::Relaxed)
+    }
+
+    /// Adds an entry, or updates the waker of an existing one, so that a
+    /// future that is polled repeatedly only has one entry.
+    fn register(&self, key: Key, waker: &Waker) {
+        let mut entries = self.entries.lock().unwrap_or_else(PoisonError::into_inner);
+        match entries.entry(key) {
+            Entry::Occupied(mut entry) => {
+                if !entry.get().will_wake(waker) {
+                    entry.insert(waker.clone());
+                }
+            }
+            Entry::Vacant(entry) => {
+                entry.insert(waker.clone());
+                self.added.notify_one();
+            }
+        }
+    }
+
+    /// Removes an entry, if it hasn't expired yet.
+    fn remove(&self, key: Key) {
+        let mut entries = self.entries.lock().unwrap_or_else(PoisonError::into_inner);
+        entries.remove(&key);
+    }
+
+    fn run(&self) {
+        let mut entries = self.entries.lock().unwrap_or_else(PoisonError::into_inner);
+        loop {
+            let now = Instant::now();
+            let pending = entries.split_off(&(now, u64::MAX));
+            let expired = std::mem::replace(&mut *entries, pending);
+
+            // Wake up the futures without holding the lock, since waking may
+            // poll them right away, and polling may register a new entry.
+            if !expired.is_empty() {
+                drop(entries);
+                expired.into_values().for_each(Waker::wake);
+                entries = self.entries.lock().unwrap_or_else(PoisonError::into_inner);
+                continue;
+            }
+
+            let next_deadline = entries.keys().next().map(|&(deadline, _)| deadline);
+            entries = match next_deadline {
+                Some(deadline) => {
+                    let timeout = deadline.saturating_duration_since(now);
+                    self.added
+                        .wait_timeout(entries, timeout)
+                        .unwrap_or_else(PoisonError::into_inner)
+                        .0
+                }
+                None => self
+                    .added
+                    .wait(entries)
+                    .unwrap_or_else(PoisonError::into_inner),
+            };
+        }
+    }
+}
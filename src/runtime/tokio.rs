@@ -1,31 +1,63 @@
 use std::future::Future;
-use std::panic::AssertUnwindSafe;
+use std::time::Duration;
 
+use futures::future::{BoxFuture, Shared};
 use futures::FutureExt;
 use sealed::sealed;
-use tokio::sync::Mutex;
-use tokio::task::JoinHandle;
+use tokio::runtime::Handle;
+use tokio::task::{JoinHandle, LocalSet};
 
+use super::join::join;
 use crate::{JobReturnStatus, Monitor};
 
 /// Represents the Tokio async runtime.
+///
+/// Jobs are spawned onto the current Tokio runtime, unless they are started
+/// using [`Job::start_on`](crate::common::Job::start_on) or by a manager with
+/// a spawner, in which case they can also be spawned onto a specific runtime
+/// [`Handle`], or onto a [`LocalSet`] if they aren't `Send`.
 pub enum Tokio {}
 
-pub struct TokioHandle(Mutex<Option<JoinHandle<()>>>);
+/// The join handle of a task, shared so that it can be waited on from several
+/// places at once, and by whichever waiter is being polled.
+pub struct TokioHandle(Shared<BoxFuture<'static, ()>>);
+
+impl TokioHandle {
+    pub(super) fn new(handle: JoinHandle<()>) -> Self {
+        // If the task got cancelled for some reason, don't worry about it.
+        // Also, the task shouldn't panic because we `catch_unwind`.
+        TokioHandle(handle.map(|_| ()).boxed().shared())
+    }
+}
 
 #[sealed]
 impl super::Runtime for Tokio {
     type JobHandle = TokioHandle;
+    type Spawner = Handle;
+
+    fn sleep(duration: Duration) -> impl Future<Output = ()> + Send + 'static {
+        tokio::time::sleep(duration)
+    }
+
+    fn spawn_blocking<F>(func: F) -> TokioHandle
+    where
+        F: FnOnce() + Send + 'static,
+    {
+        TokioHandle::new(tokio::task::spawn_blocking(func))
+    }
+
+    fn spawn_blocking_on<F>(spawner: &Handle, func: F) -> TokioHandle
+    where
+        F: FnOnce() + Send + 'static,
+    {
+        TokioHandle::new(spawner.spawn_blocking(func))
+    }
 }
 
 #[sealed]
-impl super::JobHandle<Tokio> for TokioHandle {
+impl<R: super::Runtime> super::JobHandle<R> for TokioHandle {
     async fn wait(&self) {
-        if let Some(handle) = self.0.lock().await.take() {
-            // If the task got cancelled for some reason, don't worry about it.
-            // Also, the task shouldn't panic because we `catch_unwind`.
-            let _ = handle.await;
-        }
+        self.0.clone().await
     }
 }
 
@@ -36,10 +68,42 @@ where
     F::Output: Into<JobReturnStatus>,
 {
     fn spawn(self, monitor: Monitor) -> TokioHandle {
-        let handle = tokio::task::spawn(async move {
-            let result = AssertUnwindSafe(self).catch_unwind().await;
-            monitor.set_finished(result);
-        });
-        TokioHandle(Mutex::new(Some(handle)))
+        TokioHandle::new(tokio::task::spawn(super::wrap(self, monitor)))
+    }
+
+    fn spawn_on(self, spawner: &Handle, monitor: Monitor) -> TokioHandle {
+        super::SpawnOn::spawn_on(spawner, self, monitor)
+    }
+}
+
+#[sealed]
+impl<T> super::Adoptable<Tokio> for JoinHandle<T>
+where
+    T: Into<JobReturnStatus> + Send + 'static,
+{
+    fn adopt(self, monitor: Monitor) -> TokioHandle {
+        super::Spawnable::<Tokio>::spawn(join(self), monitor)
+    }
+}
+
+#[sealed]
+impl<F> super::SpawnOn<Tokio, F> for Handle
+where
+    F: Future + Send + 'static,
+    F::Output: Into<JobReturnStatus>,
+{
+    fn spawn_on(&self, future: F, monitor: Monitor) -> TokioHandle {
+        TokioHandle::new(self.spawn(super::wrap(future, monitor)))
+    }
+}
+
+#[sealed]
+impl<F> super::SpawnOn<Tokio, F> for LocalSet
+where
+    F: Future + 'static,
+    F::Output: Into<JobReturnStatus>,
+{
+    fn spawn_on(&self, future: F, monitor: Monitor) -> TokioHandle {
+        TokioHandle::new(self.spawn_local(super::wrap(future, monitor)))
     }
 }
@@ -0,0 +1,112 @@
+use std::future::Future;
+use std::time::Duration;
+
+use sealed::sealed;
+use tokio::task::{JoinHandle, LocalSet};
+
+use super::join::join;
+use super::tokio::TokioHandle;
+use super::NoSpawner;
+use crate::{JobReturnStatus, Monitor};
+
+/// Represents the Tokio async runtime, with jobs spawned onto the current
+/// [`LocalSet`] using [`spawn_local`].
+///
+/// Unlike with [`Tokio`](super::Tokio), jobs don't need to be `Send`, so they
+/// may hold an `Rc` or a non-`Send` client across `.await`s. Jobs must be
+/// started from inside a `LocalSet`, such as in [`LocalSet::run_until`], and
+/// they only make progress while the `LocalSet` is running. Blocking jobs run
+/// on Tokio's blocking thread pool as usual.
+///
+/// Jobs of this runtime are `Send + Sync` even though their futures aren't, so
+/// they can be stored in a [`Girlboss`](crate::tokio_local::Girlboss), or their
+/// monitors in a `Girlboss<K, Monitor>`.
+///
+/// # Examples
+///
+/// ```
+/// # #[tokio::main]
+/// # async fn main() {
+/// use std::rc::Rc;
+///
+/// use girlboss::tokio_local::Job;
+/// use tokio::task::LocalSet;
+///
+/// LocalSet::new()
+///     .run_until(async {
+///         let job = Job::start(|mon| async move {
+///             let message = Rc::new("Not Send");
+///             tokio::task::yield_now().await;
+///             write!(mon, "{message}");
+///         });
+///         job.wait().await.unwrap();
+///         assert_eq!(job.status().message(), "Not Send");
+///     })
+///     .await;
+/// # }
+/// ```
+///
+/// [`LocalSet`]: https://docs.rs/tokio/1/tokio/task/struct.LocalSet.html
+/// [`LocalSet::run_until`]: https://docs.rs/tokio/1/tokio/task/struct.LocalSet.html#method.run_until
+/// [`spawn_local`]: https://docs.rs/tokio/1/tokio/task/fn.spawn_local.html
+pub enum TokioLocal {}
+
+#[sealed]
+impl super::Runtime for TokioLocal {
+    type JobHandle = TokioHandle;
+    type Spawner = NoSpawner;
+
+    fn sleep(duration: Duration) -> impl Future<Output = ()> + Send + 'static {
+        tokio::time::sleep(duration)
+    }
+
+    fn spawn_blocking<F>(func: F) -> TokioHandle
+    where
+        F: FnOnce() + Send + 'static,
+    {
+        TokioHandle::new(tokio::task::spawn_blocking(func))
+    }
+
+    fn spawn_blocking_on<F>(spawner: &NoSpawner, _func: F) -> TokioHandle
+    where
+        F: FnOnce() + Send + 'static,
+    {
+        match *spawner {}
+    }
+}
+
+#[sealed]
+impl<F> super::Spawnable<TokioLocal> for F
+where
+    F: Future + 'static,
+    F::Output: Into<JobReturnStatus>,
+{
+    fn spawn(self, monitor: Monitor) -> TokioHandle {
+        TokioHandle::new(tokio::task::spawn_local(super::wrap(self, monitor)))
+    }
+
+    fn spawn_on(self, spawner: &NoSpawner, _monitor: Monitor) -> TokioHandle {
+        match *spawner {}
+    }
+}
+
+#[sealed]
+impl<T> super::Adoptable<TokioLocal> for JoinHandle<T>
+where
+    T: Into<JobReturnStatus> + 'static,
+{
+    fn adopt(self, monitor: Monitor) -> TokioHandle {
+        super::Spawnable::<TokioLocal>::spawn(join(self), monitor)
+    }
+}
+
+#[sealed]
+impl<F> super::SpawnOn<TokioLocal, F> for LocalSet
+where
+    F: Future + 'static,
+    F::Output: Into<JobReturnStatus>,
+{
+    fn spawn_on(&self, future: F, monitor: Monitor) -> TokioHandle {
+        TokioHandle::new(self.spawn_local(super::wrap(future, monitor)))
+    }
+}
@@ -1,9 +1,21 @@
 #![cfg(test)]
 
+mod dyn_job;
+mod events;
+mod id;
 mod job_actix_rt;
+mod job_custom;
+mod job_futures_executor;
+mod job_set;
+mod job_smol;
+mod job_thread;
 mod job_tokio;
+mod job_tokio_local;
 mod jobs;
 mod manager;
+mod metrics;
 mod return_status;
+mod shared;
 mod status;
+mod trace;
 mod type_assertions;
@@ -0,0 +1,78 @@
+#![cfg(feature = "tokio")]
+
+use std::time::Duration;
+
+use crate::common::DynJob;
+use crate::runtime::Tokio;
+use crate::tests::jobs;
+use crate::tokio::Job;
+use crate::{Error, Girlboss};
+
+#[tokio::test]
+async fn waits_like_the_original_job() {
+    let job = Job::start(jobs::sets_status);
+    let dyn_job = DynJob::from(job.clone());
+    assert_eq!(dyn_job.monitor(), job.monitor());
+    dyn_job.wait().await.unwrap();
+    assert!(job.is_finished());
+    assert_eq!(dyn_job.status().message(), "Custom status");
+}
+
+#[tokio::test]
+async fn reports_failure() {
+    let dyn_job = DynJob::from(Job::start(jobs::panics));
+    assert_eq!(dyn_job.wait().await, Err(Error::JobFailed));
+    assert_eq!((&dyn_job).await, Err(Error::JobFailed));
+}
+
+#[tokio::test]
+async fn wait_timeout_gives_up() {
+    let dyn_job = DynJob::from(Job::start(jobs::slow));
+    let result = dyn_job.wait_timeout(Duration::from_millis(10)).await;
+    assert_eq!(result, Err(Error::Timeout));
+    let result = dyn_job.wait_timeout(Duration::from_secs(5)).await;
+    assert_eq!(result, Ok(()));
+}
+
+#[tokio::test]
+async fn equals_only_same_job() {
+    let job = Job::start(jobs::slow);
+    let dyn_job = DynJob::from(job.clone());
+    assert_eq!(dyn_job, DynJob::from(job));
+    assert_ne!(dyn_job, DynJob::from(Job::start(jobs::slow)));
+}
+
+#[tokio::test]
+async fn manager_starts_jobs() {
+    let mut manager = Girlboss::<i32, DynJob>::new();
+    let job = manager.start::<Tokio, _, _>(1, jobs::slow).unwrap();
+    let stored = manager.get(&1).unwrap();
+    assert_eq!(stored.monitor(), job.monitor());
+    assert_eq!(
+        manager.start::<Tokio, _, _>(1, jobs::slow),
+        Err(Error::JobExists)
+    );
+    let existing = manager.start_or_get::<Tokio, _, _>(1, jobs::instant);
+    assert_eq!(existing, stored);
+    stored.wait().await.unwrap();
+}
+
+#[cfg(feature = "thread")]
+#[tokio::test]
+async fn manager_mixes_runtimes() {
+    use crate::runtime::Thread;
+
+    let mut manager = Girlboss::<&str, DynJob>::new();
+    manager
+        .start::<Tokio, _, _>("tokio", jobs::sets_status_by_return)
+        .unwrap();
+    manager
+        .start_blocking::<Thread, _, _>("thread", |_| "Blocking")
+        .unwrap();
+    manager.get("tokio").unwrap().wait().await.unwrap();
+    manager.get("thread").unwrap().wait().await.unwrap();
+    assert_eq!(
+        manager.get("thread").unwrap().status().message(),
+        "Blocking"
+    );
+}
@@ -0,0 +1,132 @@
+#![cfg(feature = "tokio")]
+
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use futures::StreamExt;
+
+use crate::tests::jobs;
+use crate::tokio::Girlboss;
+use crate::{ConflictPolicy, Event};
+
+/// Summarizes an event for easy comparison.
+fn describe(event: &Event<i32>) -> String {
+    match event {
+        Event::Started { id, .. } => format!("started {id}"),
+        Event::Reported { id, status } => format!("reported {id}: {}", status.message()),
+        Event::Finished { id, outcome, .. } => format!("finished {id}: {outcome}"),
+        Event::Evicted { id, .. } => format!("evicted {id}"),
+    }
+}
+
+#[tokio::test]
+async fn stream_receives_lifecycle_events() {
+    let mut manager = Girlboss::<i32>::new();
+    let events = manager.subscribe();
+    let job1 = manager.start(1, jobs::sets_status_by_return).unwrap();
+    let job2 = manager.start(2, jobs::fails).unwrap();
+    job1.wait().await.unwrap();
+    job2.wait().await.unwrap_err();
+    manager.cleanup(Duration::ZERO);
+    drop(manager);
+
+    // The stream ends once the manager is dropped, even though the jobs are
+    // still alive.
+    let mut events: Vec<_> = events.collect().await;
+    // The jobs run concurrently, so group the events of each job together.
+    events.sort_by_key(|event| *event.id());
+    assert_eq!(
+        events.iter().map(describe).collect::<Vec<_>>(),
+        [
+            "started 1",
+            "reported 1: Custom status by return",
+            "finished 1: true",
+            "evicted 1",
+            "started 2",
+            "reported 2: oopsie",
+            "finished 2: false",
+            "evicted 2",
+        ],
+    );
+}
+
+#[tokio::test]
+async fn callback_receives_lifecycle_events() {
+    let events = Arc::new(Mutex::new(Vec::new()));
+    let mut manager = Girlboss::<i32>::new();
+    let events_2 = events.clone();
+    manager.on_event(move |event| events_2.lock().unwrap().push(describe(event)));
+
+    let job = manager.start(1, jobs::instant).unwrap();
+    job.wait().await.unwrap();
+    let job = manager.start(1, jobs::instant).unwrap();
+    job.wait().await.unwrap();
+
+    assert_eq!(
+        *events.lock().unwrap(),
+        [
+            "started 1",
+            "finished 1: true",
+            "started 1",
+            "evicted 1",
+            "finished 1: true",
+        ],
+    );
+}
+
+#[tokio::test]
+async fn callback_can_report_to_the_job() {
+    let events = Arc::new(Mutex::new(Vec::new()));
+    let mut manager = Girlboss::<i32>::new();
+    let events_2 = events.clone();
+    manager.on_event(move |event| {
+        events_2.lock().unwrap().push(describe(event));
+        if let Event::Finished { monitor, .. } = event {
+            monitor.report("Checked");
+        }
+    });
+
+    let job = manager.start(1, jobs::instant).unwrap();
+    job.wait().await.unwrap();
+    assert_eq!(
+        *events.lock().unwrap(),
+        ["started 1", "finished 1: true", "reported 1: Checked"],
+    );
+}
+
+#[tokio::test]
+async fn history_is_evicted_when_trimmed() {
+    let events = Arc::new(Mutex::new(Vec::new()));
+    let mut manager = Girlboss::<i32>::new();
+    manager.set_conflict_policy(ConflictPolicy::KeepHistory);
+    manager.start(1, jobs::slow).unwrap();
+    manager.start(1, jobs::slow).unwrap();
+    let events_2 = events.clone();
+    manager.on_event(move |event| events_2.lock().unwrap().push(describe(event)));
+    manager.set_history_limit(0);
+    assert_eq!(*events.lock().unwrap(), ["evicted 1"]);
+}
+
+#[tokio::test]
+async fn on_finish_is_called_for_every_job() {
+    let finished = Arc::new(Mutex::new(Vec::new()));
+    let mut manager = Girlboss::<i32>::new();
+    let finished_2 = finished.clone();
+    manager.on_finish(move |id, monitor| {
+        finished_2.lock().unwrap().push((*id, monitor.succeeded()));
+    });
+
+    manager
+        .start(1, jobs::instant)
+        .unwrap()
+        .wait()
+        .await
+        .unwrap();
+    manager
+        .start(2, jobs::fails)
+        .unwrap()
+        .wait()
+        .await
+        .unwrap_err();
+    assert_eq!(*finished.lock().unwrap(), [(1, true), (2, false)]);
+}
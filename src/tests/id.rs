@@ -0,0 +1,61 @@
+use std::collections::BTreeSet;
+
+use crate::id::{Counter, IdGenerator, Ulid};
+
+#[test]
+fn counter_starts_at_zero() {
+    let mut ids = Counter::new();
+    assert_eq!(ids.next_id(), 0);
+    assert_eq!(ids.next_id(), 1);
+    assert_eq!(ids.next_id(), 2);
+}
+
+#[test]
+fn counter_wraps_around() {
+    let mut ids = Counter::starting_at(u64::MAX);
+    assert_eq!(ids.next_id(), u64::MAX);
+    assert_eq!(ids.next_id(), 0);
+}
+
+#[test]
+fn closures_are_generators() {
+    let mut next = 10;
+    let mut ids = move || {
+        next += 10;
+        next
+    };
+    assert_eq!(IdGenerator::<i32>::next_id(&mut ids), 20);
+    assert_eq!(IdGenerator::<i32>::next_id(&mut ids), 30);
+}
+
+#[test]
+fn ulid_format_makes_sense() {
+    let id = Ulid::new().next_id();
+    assert_eq!(id.len(), 26);
+    assert!(id
+        .bytes()
+        .all(|c| c.is_ascii_digit() || c.is_ascii_uppercase()));
+    assert!(!id.contains(['I', 'L', 'O', 'U']));
+}
+
+#[test]
+fn ulids_are_sorted_and_unique() {
+    let mut ids = Ulid::new();
+    let generated: Vec<_> = (0..1000).map(|_| ids.next_id()).collect();
+    let sorted: BTreeSet<_> = generated.iter().cloned().collect();
+    assert_eq!(generated, sorted.into_iter().collect::<Vec<_>>());
+}
+
+#[test]
+fn ulids_stay_sorted_when_the_random_part_overflows() {
+    // A timestamp in the future, so that all IDs use the same millisecond.
+    let timestamp = 1 << 47;
+    let mut ids = Ulid::with_last(timestamp, (1 << 80) - 2);
+    let generated: Vec<_> = (0..3).map(|_| ids.next_id()).collect();
+    assert!(generated.windows(2).all(|pair| pair[0] < pair[1]));
+}
+
+#[test]
+fn ulid_generators_differ() {
+    assert_ne!(Ulid::new().next_id(), Ulid::new().next_id());
+}
@@ -1,8 +1,12 @@
 #![cfg(feature = "actix-rt")]
 
-use crate::actix_rt::Job;
+use std::time::Duration;
+
+use futures::StreamExt;
+
+use crate::actix_rt::{Girlboss, Job, JobSet};
 use crate::tests::jobs;
-use crate::Error;
+use crate::{Error, Monitor};
 
 #[actix_rt::test]
 async fn sets_custom_status_by_return_value() {
@@ -19,3 +23,143 @@ async fn panic_is_caught() {
     assert_eq!(job.succeeded(), false);
     assert_eq!(job.status().message(), "The job panicked");
 }
+
+async fn slow(_: Monitor) {
+    actix_rt::time::sleep(Duration::from_millis(100)).await;
+}
+
+#[actix_rt::test]
+async fn wait_timeout_gives_up_on_slow_job() {
+    let job = Job::start(slow);
+    let result = job.wait_timeout(Duration::from_millis(10)).await;
+    assert_eq!(result, Err(Error::Timeout));
+    job.wait().await.unwrap();
+}
+
+#[actix_rt::test]
+#[should_panic = "from inside an async runtime"]
+async fn wait_blocking_panics_inside_arbiter() {
+    let job = Job::start(slow);
+    let _ = job.wait_blocking();
+}
+
+#[actix_rt::test]
+async fn job_set_yields_jobs_in_completion_order() {
+    let slow = Job::start(slow);
+    let instant = Job::start(jobs::instant);
+    let mut jobs: JobSet = [slow.clone(), instant.clone()].into_iter().collect();
+    assert_eq!(jobs.wait_any().await, Some(&instant));
+    assert_eq!(jobs.next().await, Some(instant));
+    assert_eq!(jobs.next().await, Some(slow));
+    assert_eq!(jobs.next().await, None);
+}
+
+#[actix_rt::test]
+async fn can_be_waited_on_from_several_tasks() {
+    let job = Job::start(slow);
+    let waiters: Vec<_> = (0..3)
+        .map(|_| {
+            let job = job.clone();
+            actix_rt::spawn(async move { job.wait().await })
+        })
+        .collect();
+    for waiter in waiters {
+        assert_eq!(waiter.await.unwrap(), Ok(()));
+    }
+    assert!(job.is_finished());
+}
+
+#[actix_rt::test]
+async fn can_be_waited_on_concurrently_in_one_task() {
+    let job = Job::start(jobs::fails);
+    let job_2 = job.clone();
+    let (a, b) = futures::join!(job.wait(), job_2.wait());
+    assert_eq!((a, b), (Err(Error::JobFailed), Err(Error::JobFailed)));
+    assert_eq!(job.wait().await, Err(Error::JobFailed));
+}
+
+#[actix_rt::test]
+async fn start_blocking_runs_function() {
+    let job = Job::start_blocking(|_| "Done blocking");
+    job.wait().await.unwrap();
+    assert_eq!(job.status().message(), "Done blocking");
+}
+
+#[actix_rt::test]
+async fn start_on_uses_the_given_arbiter() {
+    let arbiter = actix_rt::Arbiter::new();
+    let here = std::thread::current().id();
+    let job = Job::start_on(&arbiter.handle(), move |_| async move {
+        std::thread::current().id() != here
+    });
+    job.wait().await.unwrap();
+    arbiter.stop();
+}
+
+#[actix_rt::test]
+async fn start_on_stopped_arbiter_fails() {
+    let arbiter = actix_rt::Arbiter::new();
+    let handle = arbiter.handle();
+    arbiter.stop();
+    arbiter.join().unwrap();
+    let job = Job::start_on(&handle, jobs::instant);
+    assert_eq!(job.wait().await, Err(Error::JobFailed));
+    assert_eq!(job.status().message(), "The arbiter is not running");
+}
+
+/// Returns an arbiter whose runtime names its threads, including the ones
+/// that run blocking tasks.
+fn named_arbiter() -> actix_rt::Arbiter {
+    actix_rt::Arbiter::with_tokio_rt(|| {
+        tokio::runtime::Builder::new_current_thread()
+            .thread_name("arbiter")
+            .enable_all()
+            .build()
+            .unwrap()
+    })
+}
+
+#[actix_rt::test]
+async fn manager_spawner_is_used_for_blocking_jobs() {
+    let arbiter = named_arbiter();
+    let mut manager = Girlboss::<i32>::new();
+    manager.set_spawner(arbiter.handle());
+    let job = manager
+        .start_blocking(1, |_| std::thread::current().name().map(str::to_owned))
+        .unwrap();
+    job.wait().await.unwrap();
+    assert_eq!(job.status().message(), "arbiter");
+    arbiter.stop();
+}
+
+#[actix_rt::test]
+async fn manager_spawner_is_not_used_for_async_jobs() {
+    let arbiter = named_arbiter();
+    let mut manager = Girlboss::<i32>::new();
+    manager.set_spawner(arbiter.handle());
+    let job = manager
+        .start(1, |mon| async move {
+            let name = std::rc::Rc::new(std::thread::current().name().map(str::to_owned));
+            actix_rt::time::sleep(Duration::from_millis(10)).await;
+            write!(mon, "{}", name.as_deref().unwrap_or_default());
+        })
+        .unwrap();
+    job.wait().await.unwrap();
+    assert_ne!(job.status().message(), "arbiter");
+    arbiter.stop();
+}
+
+#[actix_rt::test]
+async fn adopt_tracks_spawned_task() {
+    let job = Job::adopt(
+        actix_rt::spawn(async { "Done elsewhere" }),
+        Monitor::detached(),
+    );
+    job.wait().await.unwrap();
+    assert_eq!(job.status().message(), "Done elsewhere");
+
+    let handle = actix_rt::spawn(jobs::panics(Monitor::detached()));
+    let job = Job::adopt(handle, Monitor::detached());
+    assert_eq!(job.wait().await, Err(Error::JobFailed));
+    assert_eq!(job.status().message(), "The job panicked");
+}
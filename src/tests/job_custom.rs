@@ -0,0 +1,62 @@
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::Duration;
+
+use futures::future::BoxFuture;
+
+use crate::common::Job;
+use crate::runtime::{Custom, Executor, Runtime};
+use crate::tests::jobs;
+use crate::{Error, Monitor};
+
+static SPAWNED: AtomicUsize = AtomicUsize::new(0);
+
+/// An executor that runs each future on its own thread.
+enum ThreadExecutor {}
+
+impl Executor for ThreadExecutor {
+    fn spawn(future: BoxFuture<'static, ()>) {
+        SPAWNED.fetch_add(1, Ordering::Relaxed);
+        std::thread::spawn(move || futures::executor::block_on(future));
+    }
+}
+
+type CustomJob = Job<Custom<ThreadExecutor>>;
+
+#[test]
+fn spawns_onto_executor() {
+    let spawned = SPAWNED.load(Ordering::Relaxed);
+    let job = CustomJob::start(jobs::sets_status_by_return);
+    assert!(SPAWNED.load(Ordering::Relaxed) > spawned);
+    job.wait_blocking().unwrap();
+    assert_eq!(job.status().message(), "Custom status by return");
+}
+
+#[test]
+fn panic_is_caught() {
+    let job = CustomJob::start(jobs::panics);
+    assert_eq!(
+        futures::executor::block_on(job.wait()),
+        Err(Error::JobFailed)
+    );
+    assert_eq!(job.status().message(), "The job panicked");
+}
+
+#[test]
+fn default_sleep_and_cancel_work() {
+    let job =
+        CustomJob::start(|_: Monitor| Custom::<ThreadExecutor>::sleep(Duration::from_secs(10)));
+    assert_eq!(
+        job.wait_blocking_timeout(Duration::from_millis(10)),
+        Err(Error::Timeout),
+    );
+    job.cancel();
+    assert_eq!(job.wait_blocking(), Err(Error::JobFailed));
+    assert_eq!(job.status().message(), "The job was cancelled");
+}
+
+#[test]
+fn default_spawn_blocking_works() {
+    let job = CustomJob::start_blocking(|_| "Done blocking");
+    futures::executor::block_on(job.wait()).unwrap();
+    assert_eq!(job.status().message(), "Done blocking");
+}
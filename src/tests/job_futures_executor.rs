@@ -0,0 +1,73 @@
+#![cfg(feature = "futures-executor")]
+
+use std::time::Duration;
+
+use futures::channel::oneshot;
+
+use crate::runtime::{LocalPool, Runtime, ThreadPool};
+use crate::tests::jobs;
+use crate::{local_pool, thread_pool, Error, Monitor};
+
+#[test]
+fn thread_pool_runs_jobs() {
+    let job = thread_pool::Job::start(jobs::sets_status_by_return);
+    job.wait_blocking().unwrap();
+    assert_eq!(job.status().message(), "Custom status by return");
+}
+
+#[test]
+fn thread_pool_catches_panics() {
+    let job = thread_pool::Job::start(jobs::panics);
+    assert_eq!(job.wait_blocking(), Err(Error::JobFailed));
+    assert_eq!(job.status().message(), "The job panicked");
+}
+
+#[test]
+fn thread_pool_wait_timeout_gives_up() {
+    let job = thread_pool::Job::start(|_: Monitor| ThreadPool::sleep(Duration::from_millis(100)));
+    let result = LocalPool::run_until(job.wait_timeout(Duration::from_millis(10)));
+    assert_eq!(result, Err(Error::Timeout));
+    LocalPool::run_until(job.wait()).unwrap();
+}
+
+#[test]
+fn local_pool_only_runs_jobs_when_driven() {
+    let (sender, receiver) = oneshot::channel::<()>();
+    let job = local_pool::Job::start(|mon| async move {
+        mon.report("Waiting");
+        let _ = receiver.await;
+        "Done"
+    });
+    assert_eq!(job.status().message(), "Starting job");
+
+    LocalPool::run_until_stalled();
+    assert_eq!(job.status().message(), "Waiting");
+    assert!(!job.is_finished());
+
+    sender.send(()).unwrap();
+    LocalPool::run_until_stalled();
+    assert_eq!(job.status().message(), "Done");
+    assert!(job.is_finished());
+}
+
+#[test]
+fn local_pool_runs_non_send_jobs() {
+    let job = local_pool::Job::start(|mon| async move {
+        let value = std::rc::Rc::new(42);
+        LocalPool::sleep(Duration::from_millis(10)).await;
+        write!(mon, "The value is {value}");
+    });
+    LocalPool::run_until(job.wait()).unwrap();
+    assert_eq!(job.status().message(), "The value is 42");
+}
+
+#[test]
+fn local_pool_manager_queues_jobs() {
+    let mut manager = local_pool::Girlboss::<i32>::new();
+    manager.set_conflict_policy(crate::ConflictPolicy::Queue);
+    let job1 = manager.start(1, jobs::sets_status_by_return).unwrap();
+    let job1_2 = manager.start(1, jobs::fails).unwrap();
+    LocalPool::run();
+    assert_eq!(job1.outcome(), Some(true));
+    assert_eq!(job1_2.outcome(), Some(false));
+}
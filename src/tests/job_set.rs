@@ -0,0 +1,72 @@
+#![cfg(feature = "tokio")]
+
+use std::time::Duration;
+
+use futures::StreamExt;
+
+use crate::tests::jobs;
+use crate::tokio::{Job, JobSet};
+use crate::Error;
+
+#[tokio::test]
+async fn wait_all_waits_for_every_job() {
+    let jobs: JobSet = [Job::start(jobs::slow), Job::start(jobs::instant)]
+        .into_iter()
+        .collect();
+    jobs.wait_all().await.unwrap();
+    assert!(jobs.iter().all(Job::is_finished));
+}
+
+#[tokio::test]
+async fn wait_all_fails_if_any_job_fails() {
+    let jobs: JobSet = [Job::start(jobs::slow), Job::start(jobs::fails)]
+        .into_iter()
+        .collect();
+    assert_eq!(jobs.wait_all().await, Err(Error::JobFailed));
+    assert!(jobs.iter().all(Job::is_finished));
+}
+
+#[tokio::test]
+async fn wait_all_timeout_gives_up() {
+    let jobs: JobSet = [Job::start(jobs::slow)].into_iter().collect();
+    let result = jobs.wait_all_timeout(Duration::from_millis(10)).await;
+    assert_eq!(result, Err(Error::Timeout));
+}
+
+#[tokio::test]
+async fn wait_any_returns_first_finished_job() {
+    let slow = Job::start(jobs::slow);
+    let instant = Job::start(jobs::instant);
+    let jobs: JobSet = [slow.clone(), instant.clone()].into_iter().collect();
+    assert_eq!(jobs.wait_any().await, Some(&instant));
+    assert!(!slow.is_finished());
+    assert_eq!(jobs.len(), 2);
+}
+
+#[tokio::test]
+async fn wait_any_on_empty_set_returns_none() {
+    let jobs = JobSet::new();
+    assert_eq!(jobs.wait_any().await, None);
+}
+
+#[tokio::test]
+async fn stream_yields_jobs_in_completion_order() {
+    let slow = Job::start(jobs::slow);
+    let instant = Job::start(jobs::instant);
+    let mut jobs: JobSet = [slow.clone(), instant.clone()].into_iter().collect();
+    assert_eq!(jobs.next().await, Some(instant));
+    assert_eq!(jobs.next().await, Some(slow));
+    assert_eq!(jobs.next().await, None);
+    assert!(jobs.is_empty());
+}
+
+#[tokio::test]
+async fn jobs_whose_task_is_gone_count_as_finished() {
+    let local = tokio::task::LocalSet::new();
+    let job = Job::start_on(&local, |_| std::future::pending::<()>());
+    drop(local);
+    let mut jobs: JobSet = [job.clone()].into_iter().collect();
+    assert_eq!(jobs.wait_all().await, Err(Error::JobFailed));
+    assert_eq!(jobs.wait_any().await, Some(&job));
+    assert_eq!(jobs.next().await, Some(job));
+}
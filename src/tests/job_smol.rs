@@ -0,0 +1,54 @@
+#![cfg(feature = "smol")]
+
+use std::time::Duration;
+
+use smol::{block_on, unblock, Timer};
+
+use crate::smol::Job;
+use crate::tests::jobs;
+use crate::tests::shared::shared_job_tests;
+use crate::{Error, Monitor};
+
+shared_job_tests!();
+
+async fn sleep(duration: Duration) {
+    Timer::after(duration).await;
+}
+
+// The shared test jobs that sleep use Tokio's timer, so use smol's instead.
+
+async fn slow(_: Monitor) {
+    Timer::after(Duration::from_millis(100)).await;
+}
+
+async fn sets_status(mon: Monitor) {
+    mon.report("Custom status");
+    slow(mon).await;
+}
+
+async fn sets_status_with_write(mon: Monitor) {
+    write!(mon, "trans rights");
+    slow(mon).await;
+}
+
+async fn sets_status_with_write_fmt(mon: Monitor) {
+    let number = 42;
+    write!(mon, "tends to {number}");
+    slow(mon).await;
+}
+
+#[test]
+fn adopt_tracks_spawned_task() {
+    smol::block_on(async {
+        let job = Job::adopt(smol::spawn(async { "Done elsewhere" }), Monitor::detached());
+        job.wait().await.unwrap();
+        assert_eq!(job.status().message(), "Done elsewhere");
+
+        let job = Job::adopt(
+            smol::spawn(jobs::panics(Monitor::detached())),
+            Monitor::detached(),
+        );
+        assert_eq!(job.wait().await, Err(Error::JobFailed));
+        assert_eq!(job.status().message(), "The job panicked");
+    });
+}
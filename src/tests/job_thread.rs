@@ -0,0 +1,84 @@
+#![cfg(feature = "thread")]
+
+use std::time::Duration;
+
+use crate::runtime::{Runtime, Thread};
+use crate::tests::jobs;
+use crate::thread::{Girlboss, Job};
+use crate::{Error, Monitor};
+
+async fn slow(_: Monitor) {
+    Thread::sleep(Duration::from_millis(100)).await;
+}
+
+#[test]
+fn sets_custom_status_by_return_value() {
+    let job = Job::start(jobs::sets_status_by_return);
+    job.wait_blocking().unwrap();
+    assert_eq!(job.status().message(), "Custom status by return");
+}
+
+#[test]
+fn panic_is_caught() {
+    let job = Job::start(jobs::panics);
+    assert_eq!(Thread::block_on(job.wait()), Err(Error::JobFailed));
+    assert_eq!(job.outcome(), Some(false));
+    assert_eq!(job.status().message(), "The job panicked");
+}
+
+#[test]
+fn sleep_waits() {
+    let job = Job::start(slow);
+    assert!(!job.is_finished());
+    job.wait_blocking().unwrap();
+    assert!(job.monitor().elapsed() >= Duration::from_millis(100));
+    // Generous, so that this doesn't fail on a loaded machine.
+    assert!(job.monitor().elapsed() < Duration::from_secs(5));
+}
+
+#[test]
+fn wait_timeout_gives_up_on_slow_job() {
+    let job = Job::start(slow);
+    let result = Thread::block_on(job.wait_timeout(Duration::from_millis(10)));
+    assert_eq!(result, Err(Error::Timeout));
+    Thread::block_on(job.wait()).unwrap();
+}
+
+#[test]
+fn cancel_stops_job() {
+    let job = Job::start(slow);
+    job.cancel();
+    assert_eq!(job.wait_blocking(), Err(Error::JobFailed));
+    assert_eq!(job.status().message(), "The job was cancelled");
+}
+
+#[test]
+fn start_blocking_runs_function() {
+    let job = Job::start_blocking(|mon| {
+        mon.report("Working");
+        std::thread::sleep(Duration::from_millis(10));
+        "Done"
+    });
+    Thread::block_on(job.wait()).unwrap();
+    assert_eq!(job.status().message(), "Done");
+}
+
+#[test]
+fn manager_works_without_runtime() {
+    let mut manager = Girlboss::<i32>::new();
+    let job = manager.start(1, slow).unwrap();
+    assert_eq!(manager.start(1, slow), Err(Error::JobExists));
+    job.wait_blocking().unwrap();
+    assert_eq!(manager.get(&1), Some(job));
+}
+
+#[test]
+fn adopt_tracks_remote_handle() {
+    use futures::FutureExt;
+
+    let (task, handle) = async { "Done elsewhere" }.remote_handle();
+    std::thread::spawn(move || futures::executor::block_on(task));
+    let job = Job::adopt(handle, Monitor::detached());
+    job.wait_blocking().unwrap();
+    assert_eq!(job.status().message(), "Done elsewhere");
+}
@@ -1,159 +1,156 @@
 #![cfg(feature = "tokio")]
 
-use std::time::{Duration, Instant};
+use std::future::Future;
+use std::time::Duration;
 
 use tokio::time::sleep;
 
-use crate::tests::jobs;
+use crate::common::DynJob;
+use crate::tests::jobs::{
+    self, sets_status, sets_status_with_write, sets_status_with_write_fmt, slow,
+};
+use crate::tests::shared::shared_job_tests;
 use crate::tokio::Job;
-use crate::Error;
+use crate::{Error, Monitor};
 
-#[tokio::test]
-async fn debug_impl_makes_sense() {
-    let job = Job::start(jobs::instant);
-    let repr = format!("{job:?}");
-    assert!(repr.starts_with("Job("));
-    assert!(repr.contains("0x"));
-}
+shared_job_tests!();
 
-#[tokio::test]
-async fn pointer_impl_makes_sense() {
-    let job = Job::start(jobs::instant);
-    let repr = format!("{job:p}");
-    assert!(repr.starts_with("0x"));
+/// Runs `future` on a new current-thread runtime, like `#[tokio::test]`.
+fn block_on<F: Future>(future: F) -> F::Output {
+    tokio::runtime::Builder::new_current_thread()
+        .enable_all()
+        .build()
+        .unwrap()
+        .block_on(future)
 }
 
-#[tokio::test]
-async fn equals_self() {
-    let job = Job::start(jobs::slow);
-    assert_eq!(job, job);
-    assert_eq!(job, job.clone());
+async fn unblock<T: Send + 'static>(func: impl FnOnce() -> T + Send + 'static) -> T {
+    tokio::task::spawn_blocking(func).await.unwrap()
 }
 
 #[tokio::test]
-async fn does_not_equal_other() {
-    let job1 = Job::start(jobs::slow);
-    let job2 = Job::start(jobs::slow);
-    assert_ne!(job1, job2);
-}
-
-#[tokio::test]
-async fn sets_default_status() {
-    let job = Job::start(jobs::instant);
-    assert_eq!(job.status().message(), "Starting job");
+#[should_panic = "from inside an async runtime"]
+async fn wait_blocking_panics_inside_runtime() {
+    let job = Job::start(jobs::slow);
+    let _ = job.wait_blocking();
 }
 
 #[tokio::test]
-async fn sets_custom_status() {
-    let job = Job::start(jobs::sets_status);
-    sleep(Duration::from_millis(50)).await;
-    assert_eq!(job.status().message(), "Custom status");
+async fn start_on_uses_the_given_runtime() {
+    let background = tokio::runtime::Builder::new_multi_thread()
+        .thread_name("background")
+        .enable_time()
+        .build()
+        .unwrap();
+    let job = Job::start_on(background.handle(), |_| async {
+        std::thread::current().name().map(str::to_owned)
+    });
+    job.wait().await.unwrap();
+    assert_eq!(job.status().message(), "background");
+    background.shutdown_background();
 }
 
 #[tokio::test]
-async fn sets_custom_status_with_write() {
-    let job = Job::start(jobs::sets_status_with_write);
-    sleep(Duration::from_millis(50)).await;
-    assert_eq!(job.status().message(), "trans rights");
+async fn start_on_local_set_allows_non_send_jobs() {
+    let local = tokio::task::LocalSet::new();
+    let job = Job::start_on(&local, |mon| async move {
+        let message = std::rc::Rc::new("Not Send");
+        sleep(Duration::from_millis(10)).await;
+        write!(mon, "{message}");
+    });
+    local.run_until(job.wait()).await.unwrap();
+    assert_eq!(job.status().message(), "Not Send");
 }
 
 #[tokio::test]
-async fn sets_custom_status_with_write_fmt() {
-    let job = Job::start(jobs::sets_status_with_write_fmt);
-    sleep(Duration::from_millis(50)).await;
-    assert_eq!(job.status().message(), "tends to 42");
+async fn awaiting_fails_when_the_task_is_dropped() {
+    let local = tokio::task::LocalSet::new();
+    let job = Job::start_on(&local, |_| std::future::pending::<()>());
+    drop(local);
+    assert_eq!(job.wait().await, Err(Error::JobFailed));
+    assert_eq!(job.future().await, Err(Error::JobFailed));
+    assert_eq!(DynJob::from(job).await, Err(Error::JobFailed));
 }
 
 #[tokio::test]
-async fn sets_custom_status_by_return_value() {
-    let job = Job::start(jobs::sets_status_by_return);
+async fn adopt_tracks_spawned_task() {
+    let monitor = Monitor::detached();
+    let mon = monitor.clone();
+    let handle = tokio::spawn(async move {
+        write!(mon, "Working elsewhere");
+        sleep(Duration::from_millis(10)).await;
+    });
+    let job = Job::adopt(handle, monitor);
     job.wait().await.unwrap();
-    assert_eq!(job.status().message(), "Custom status by return");
+    assert_eq!(job.status().message(), "Working elsewhere");
 }
 
 #[tokio::test]
-async fn outcome_is_none_when_in_progress() {
-    let job = Job::start(jobs::slow);
-    assert_eq!(job.outcome(), None);
-    assert_eq!(job.succeeded(), false);
-}
-
-#[tokio::test]
-async fn outcome_is_false_when_failed() {
-    let job = Job::start(jobs::fails);
+async fn adopt_records_panic() {
+    let handle = tokio::spawn(jobs::panics(Monitor::detached()));
+    let job = Job::adopt(handle, Monitor::detached());
     assert_eq!(job.wait().await, Err(Error::JobFailed));
-    assert_eq!(job.outcome(), Some(false));
-    assert_eq!(job.succeeded(), false);
-    assert_eq!(job.status().message(), "oopsie");
+    assert_eq!(job.status().message(), "The job panicked");
 }
 
 #[tokio::test]
-async fn panic_is_caught() {
-    let job = Job::start(jobs::panics);
+async fn cancelling_adopted_job_aborts_task() {
+    let handle = tokio::spawn(std::future::pending::<()>());
+    let abort_handle = handle.abort_handle();
+    let job = Job::adopt(handle, Monitor::detached());
+    job.cancel();
     assert_eq!(job.wait().await, Err(Error::JobFailed));
-    assert_eq!(job.outcome(), Some(false));
-    assert_eq!(job.succeeded(), false);
-    assert_eq!(job.status().message(), "The job panicked");
+    assert_eq!(job.status().message(), "The job was cancelled");
+    sleep(Duration::from_millis(10)).await;
+    assert!(abort_handle.is_finished());
 }
 
 #[tokio::test]
-async fn outcome_is_true_when_succeeded() {
+#[should_panic = "already belongs to a job"]
+async fn adopt_rejects_attached_monitor() {
     let job = Job::start(jobs::instant);
-    job.wait().await.unwrap();
-    assert_eq!(job.outcome(), Some(true));
-    assert_eq!(job.succeeded(), true);
-}
-
-#[tokio::test]
-async fn started_time_makes_sense() {
-    let before = Instant::now();
-    let job = Job::start(jobs::slow);
-    let after = Instant::now();
-    assert!(before <= job.monitor().started_at());
-    assert!(job.monitor().started_at() <= after);
+    Job::adopt(tokio::spawn(async {}), job.monitor().clone());
 }
 
 #[tokio::test]
-async fn finished_time_makes_sense() {
-    let job = Job::start(jobs::slow);
-    sleep(Duration::from_millis(50)).await;
-    let before = Instant::now();
-    job.wait().await.unwrap();
-    let after = Instant::now();
-    let finished_at = job.monitor().finished_at().unwrap();
-    assert!(before <= finished_at);
-    assert!(finished_at <= after);
+#[should_panic = "already belongs to a job"]
+async fn adopt_rejects_monitor_of_blocking_job() {
+    let job = Job::start_blocking(|_| ());
+    Job::adopt(tokio::spawn(async {}), job.monitor().clone());
 }
 
 #[tokio::test]
-async fn elapsed_time_makes_sense() {
-    let job = Job::start(jobs::slow);
+async fn current_monitor_is_set_inside_job() {
+    assert_eq!(Monitor::current(), None);
+    let job = Job::start(|mon| async move {
+        assert_eq!(Monitor::current(), Some(mon.clone()));
+        sleep(Duration::from_millis(10)).await;
+        assert_eq!(Monitor::current(), Some(mon.clone()));
+        let spawned = tokio::spawn(async { Monitor::current() }).await.unwrap();
+        spawned.is_none()
+    });
     job.wait().await.unwrap();
-    assert!(job.monitor().elapsed() >= Duration::from_millis(100));
-    assert!(job.monitor().elapsed() <= Duration::from_millis(150));
+    assert_eq!(Monitor::current(), None);
 }
 
 #[tokio::test]
-async fn elapsed_time_is_retained_after_finish() {
-    let job = Job::start(jobs::slow);
+async fn current_monitor_is_set_inside_blocking_job() {
+    let job = Job::start_blocking(|mon| Monitor::current() == Some(mon));
     job.wait().await.unwrap();
-    sleep(Duration::from_millis(200)).await;
-    assert!(job.monitor().elapsed() >= Duration::from_millis(100));
-    assert!(job.monitor().elapsed() <= Duration::from_millis(150));
 }
 
 #[tokio::test]
-async fn elapsed_time_makes_sense_before_finish() {
-    let job = Job::start(jobs::slow);
-    assert!(job.monitor().elapsed() <= Duration::from_millis(50));
-}
+async fn report_macro_reports_to_current_job() {
+    fn helper(step: i32) {
+        crate::report!("Step {step}");
+    }
 
-#[tokio::test]
-async fn is_finished_is_correct() {
-    let job = Job::start(jobs::slow);
-    assert_eq!(job.is_finished(), false);
-    sleep(Duration::from_millis(150)).await;
-    assert_eq!(job.is_finished(), true);
+    helper(0);
+    let job = Job::start(|_| async {
+        helper(1);
+        sleep(Duration::from_millis(10)).await;
+        helper(2);
+    });
     job.wait().await.unwrap();
-    assert_eq!(job.is_finished(), true);
+    assert_eq!(job.status().message(), "Step 2");
 }
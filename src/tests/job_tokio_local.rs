@@ -0,0 +1,116 @@
+#![cfg(feature = "tokio-local")]
+
+use std::future::Future;
+use std::rc::Rc;
+use std::time::Duration;
+
+use tokio::task::LocalSet;
+use tokio::time::sleep;
+
+use crate::runtime::TokioLocal;
+use crate::tests::jobs;
+use crate::tokio_local::{Girlboss, Job};
+use crate::{Error, Monitor};
+
+// Jobs are spawned with `spawn_local`, so tests run inside a `LocalSet`.
+async fn in_local_set<F: Future>(future: F) -> F::Output {
+    LocalSet::new().run_until(future).await
+}
+
+async fn not_send(mon: Monitor) {
+    let message = Rc::new("Not Send");
+    sleep(Duration::from_millis(10)).await;
+    write!(mon, "{message}");
+}
+
+#[tokio::test]
+async fn runs_non_send_jobs() {
+    in_local_set(async {
+        let job = Job::start(not_send);
+        job.wait().await.unwrap();
+        assert_eq!(job.status().message(), "Not Send");
+    })
+    .await;
+}
+
+#[tokio::test]
+async fn sets_custom_status_by_return_value() {
+    in_local_set(async {
+        let job = Job::start(jobs::sets_status_by_return);
+        job.wait().await.unwrap();
+        assert_eq!(job.status().message(), "Custom status by return");
+    })
+    .await;
+}
+
+#[tokio::test]
+async fn panic_is_caught() {
+    in_local_set(async {
+        let job = Job::start(jobs::panics);
+        assert_eq!(job.wait().await, Err(Error::JobFailed));
+        assert_eq!(job.status().message(), "The job panicked");
+    })
+    .await;
+}
+
+#[tokio::test]
+async fn can_be_cancelled() {
+    in_local_set(async {
+        let job = Job::start(jobs::slow);
+        job.cancel();
+        assert_eq!(job.wait().await, Err(Error::JobFailed));
+        assert_eq!(job.status().message(), "The job was cancelled");
+    })
+    .await;
+}
+
+#[tokio::test]
+async fn wait_timeout_gives_up() {
+    in_local_set(async {
+        let job = Job::start(jobs::slow);
+        let result = job.wait_timeout(Duration::from_millis(10)).await;
+        assert_eq!(result, Err(Error::Timeout));
+        job.wait().await.unwrap();
+    })
+    .await;
+}
+
+#[tokio::test]
+async fn start_blocking_runs_function() {
+    in_local_set(async {
+        let job = Job::start_blocking(|_| "Done blocking");
+        job.wait().await.unwrap();
+        assert_eq!(job.status().message(), "Done blocking");
+    })
+    .await;
+}
+
+#[tokio::test]
+async fn start_on_local_set() {
+    let local = LocalSet::new();
+    let job = Job::start_on(&local, not_send);
+    local.run_until(job.wait()).await.unwrap();
+    assert_eq!(job.status().message(), "Not Send");
+}
+
+#[tokio::test]
+async fn can_be_stored_in_manager() {
+    in_local_set(async {
+        let mut manager = Girlboss::<i32>::new();
+        let job = manager.start(1, not_send).unwrap();
+        job.wait().await.unwrap();
+        assert_eq!(manager.get(&1), Some(job));
+    })
+    .await;
+}
+
+#[tokio::test]
+async fn can_be_stored_in_monitor_manager() {
+    in_local_set(async {
+        let mut manager = crate::Girlboss::<i32, Monitor>::new();
+        let job = manager.start::<TokioLocal, _, _>(1, not_send).unwrap();
+        job.wait().await.unwrap();
+        assert_eq!(manager.get(&1).unwrap().status().message(), "Not Send");
+    })
+    .await;
+}
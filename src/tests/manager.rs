@@ -1,11 +1,15 @@
 #![cfg(feature = "tokio")]
 
+use std::sync::{Arc, Mutex};
 use std::time::Duration;
 
+use tokio::time::sleep;
+
+use crate::id::{Counter, Ulid};
 use crate::runtime::Tokio;
 use crate::tests::jobs;
 use crate::tokio::Girlboss;
-use crate::{Error, Monitor};
+use crate::{ConflictPolicy, Error, Event, JobFilter, Monitor, SortBy};
 
 #[tokio::test]
 async fn can_start_and_get_jobs() {
@@ -82,3 +86,470 @@ async fn store_monitors() {
 
     assert_ne!(mon1, mon2);
 }
+
+#[tokio::test]
+async fn spawned_cleanup_removes_finished_jobs() {
+    let manager = Arc::new(Mutex::new(Girlboss::<i32>::new()));
+    let _cleanup =
+        Girlboss::spawn_cleanup::<Tokio>(&manager, Duration::from_millis(10), Duration::ZERO);
+    let job1 = manager.lock().unwrap().start(1, jobs::instant).unwrap();
+    job1.wait().await.unwrap();
+    sleep(Duration::from_millis(50)).await;
+    assert_eq!(manager.lock().unwrap().get(&1), None);
+}
+
+#[tokio::test]
+async fn spawned_cleanup_can_be_stopped() {
+    let manager = Arc::new(Mutex::new(Girlboss::<i32>::new()));
+    let cleanup =
+        Girlboss::spawn_cleanup::<Tokio>(&manager, Duration::from_millis(10), Duration::ZERO);
+    let cleanup_job = cleanup.job().clone();
+    cleanup.stop().await;
+    assert!(cleanup_job.is_finished());
+
+    let job1 = manager.lock().unwrap().start(1, jobs::instant).unwrap();
+    job1.wait().await.unwrap();
+    sleep(Duration::from_millis(50)).await;
+    assert_eq!(manager.lock().unwrap().get(&1), Some(job1));
+}
+
+#[tokio::test]
+async fn spawned_cleanup_stops_when_manager_is_dropped() {
+    let manager = Arc::new(Mutex::new(Girlboss::<i32>::new()));
+    let cleanup =
+        Girlboss::spawn_cleanup::<Tokio>(&manager, Duration::from_millis(10), Duration::ZERO);
+    drop(manager);
+    cleanup.job().wait().await.unwrap();
+}
+
+#[tokio::test]
+async fn iterates_jobs_by_id() {
+    let mut manager = Girlboss::<i32>::new();
+    let job2 = manager.start(2, jobs::slow).unwrap();
+    let job1 = manager.start(1, jobs::slow).unwrap();
+    let job3 = manager.start(3, jobs::slow).unwrap();
+    assert_eq!(manager.len(), 3);
+    let all: Vec<_> = manager.iter().collect();
+    assert_eq!(all, [(&1, &job1), (&2, &job2), (&3, &job3)]);
+    let some: Vec<_> = manager.range(2..).collect();
+    assert_eq!(some, [(&2, &job2), (&3, &job3)]);
+}
+
+#[tokio::test]
+async fn filters_jobs_by_state() {
+    let mut manager = Girlboss::<i32>::new();
+    let running = manager.start(1, jobs::slow).unwrap();
+    let succeeded = manager.start(2, jobs::instant).unwrap();
+    let failed = manager.start(3, jobs::fails).unwrap();
+    succeeded.wait().await.unwrap();
+    failed.wait().await.unwrap_err();
+
+    let ids = |filter| {
+        manager
+            .filter(filter)
+            .map(|(id, _)| *id)
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(ids(JobFilter::All), [1, 2, 3]);
+    assert_eq!(ids(JobFilter::Running), [1]);
+    assert_eq!(ids(JobFilter::Finished), [2, 3]);
+    assert_eq!(ids(JobFilter::Succeeded), [2]);
+    assert_eq!(ids(JobFilter::Failed), [3]);
+    assert!(!running.is_finished());
+}
+
+#[tokio::test]
+async fn sorts_jobs_by_time() {
+    let mut manager = Girlboss::<i32>::new();
+    let job1 = manager.start(1, jobs::slow).unwrap();
+    sleep(Duration::from_millis(10)).await;
+    let job2 = manager.start(2, jobs::instant).unwrap();
+    sleep(Duration::from_millis(10)).await;
+    let job3 = manager.start(3, jobs::instant).unwrap();
+    job3.wait().await.unwrap();
+    job2.wait().await.unwrap();
+
+    let ids = |sort| {
+        manager
+            .sorted(JobFilter::All, sort)
+            .into_iter()
+            .map(|(id, _)| *id)
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(ids(SortBy::StartedAt), [3, 2, 1]);
+    assert_eq!(ids(SortBy::FinishedAt), [1, 3, 2]);
+    assert!(!job1.is_finished());
+}
+
+#[tokio::test]
+async fn paginates_jobs() {
+    let mut manager = Girlboss::<i32>::new();
+    for id in 0..5 {
+        let job = manager.start(id, jobs::instant).unwrap();
+        job.wait().await.unwrap();
+    }
+    manager.start(5, jobs::slow).unwrap();
+
+    let page = manager.page(None, 2, JobFilter::All);
+    assert_eq!(
+        page.items().iter().map(|(id, _)| **id).collect::<Vec<_>>(),
+        [0, 1]
+    );
+    assert_eq!(page.next_cursor(), Some(&1));
+    let page = manager.page(Some(&1), 10, JobFilter::All);
+    assert_eq!(page.items().len(), 4);
+    assert_eq!(page.next_cursor(), None);
+    let page = manager.page(Some(&2), 1, JobFilter::Running);
+    assert_eq!(
+        page.items().iter().map(|(id, _)| **id).collect::<Vec<_>>(),
+        [5]
+    );
+    assert_eq!(page.next_cursor(), None);
+}
+
+#[tokio::test]
+async fn start_auto_requires_id_generator() {
+    let mut manager = Girlboss::<u64>::new();
+    let result = manager.start_auto(jobs::instant);
+    assert_eq!(result, Err(Error::NoIdGenerator));
+}
+
+#[tokio::test]
+async fn start_auto_skips_used_ids() {
+    let mut manager = Girlboss::<u64>::new();
+    manager.set_id_generator(Counter::new());
+    let job1 = manager.start(1_u64, jobs::instant).unwrap();
+    job1.wait().await.unwrap();
+    let (id0, job0) = manager.start_auto(jobs::slow).unwrap();
+    let (id2, job2) = manager.start_auto(jobs::slow).unwrap();
+    assert_eq!((id0, id2), (0, 2));
+    assert_eq!(manager.get(&0), Some(job0));
+    assert_eq!(manager.get(&1), Some(job1));
+    assert_eq!(manager.get(&2), Some(job2));
+}
+
+#[tokio::test]
+async fn start_auto_gives_up_on_repeated_ids() {
+    let mut manager = Girlboss::<u64>::new();
+    manager.set_id_generator(|| 7);
+    manager.start_auto(jobs::slow).unwrap();
+    let result = manager.start_auto(jobs::slow);
+    assert_eq!(result, Err(Error::JobExists));
+}
+
+#[tokio::test]
+async fn start_auto_with_monitors() {
+    let mut manager = crate::Girlboss::<String, Monitor>::new();
+    manager.set_id_generator(Ulid::new());
+    let (id, job) = manager.start_auto::<Tokio, _, _>(jobs::instant).unwrap();
+    assert_eq!(manager.get(&id), Some(job.monitor().clone()));
+}
+
+#[tokio::test]
+async fn start_or_get_reuses_running_job() {
+    let mut manager = Girlboss::<i32>::new();
+    let job1 = manager.start_or_get(1, jobs::slow);
+    let job1_2 = manager.start_or_get(1, jobs::slow);
+    assert_eq!(job1, job1_2);
+}
+
+#[tokio::test]
+async fn start_or_get_replaces_finished_job() {
+    let mut manager = Girlboss::<i32>::new();
+    let job1 = manager.start_or_get(1, jobs::instant);
+    job1.wait().await.unwrap();
+    let job1_2 = manager.start_or_get(1, jobs::instant);
+    assert_ne!(job1, job1_2);
+    assert_eq!(manager.get(&1), Some(job1_2));
+}
+
+#[tokio::test]
+async fn start_or_get_within_reuses_fresh_job() {
+    let mut manager = Girlboss::<i32>::new();
+    let max_age = Duration::from_millis(50);
+    let job1 = manager.start_or_get_within(1, max_age, jobs::instant);
+    job1.wait().await.unwrap();
+    let job1_2 = manager.start_or_get_within(1, max_age, jobs::instant);
+    assert_eq!(job1, job1_2);
+
+    sleep(max_age).await;
+    let job1_3 = manager.start_or_get_within(1, max_age, jobs::instant);
+    assert_ne!(job1, job1_3);
+}
+
+#[tokio::test]
+async fn start_or_get_with_monitors() {
+    let mut manager = crate::Girlboss::<i32, Monitor>::new();
+    let mon1 = manager.start_or_get::<Tokio, _, _>(1, jobs::slow);
+    let mon1_2 = manager.start_or_get::<Tokio, _, _>(1, jobs::slow);
+    assert_eq!(mon1, mon1_2);
+    assert_eq!(manager.get(&1), Some(mon1));
+}
+
+#[tokio::test]
+async fn replace_policy_cancels_running_job() {
+    let mut manager = Girlboss::<i32>::new();
+    manager.set_conflict_policy(ConflictPolicy::Replace);
+    let job1 = manager.start(1, jobs::slow).unwrap();
+    let job1_2 = manager.start(1, jobs::instant).unwrap();
+    assert_eq!(manager.get(&1), Some(job1_2.clone()));
+    job1_2.wait().await.unwrap();
+    assert_eq!(job1.outcome(), Some(false));
+    assert_eq!(job1.status().message(), "The job was cancelled");
+    assert!(job1_2.monitor().elapsed() < Duration::from_millis(100));
+}
+
+#[tokio::test]
+async fn queue_policy_waits_for_running_job() {
+    let mut manager = Girlboss::<i32>::new();
+    manager.set_conflict_policy(ConflictPolicy::Queue);
+    let job1 = manager.start(1, jobs::slow).unwrap();
+    let job1_2 = manager.start(1, jobs::instant).unwrap();
+    assert_eq!(manager.get(&1), Some(job1_2.clone()));
+    assert_eq!(
+        job1_2.status().message(),
+        "Waiting for the previous job to finish",
+    );
+    job1_2.wait().await.unwrap();
+    assert_eq!(job1.outcome(), Some(true));
+    assert!(job1.monitor().finished_at() <= job1_2.monitor().finished_at());
+}
+
+#[tokio::test]
+async fn replace_policy_cancels_queued_jobs_and_what_they_wait_for() {
+    let mut manager = Girlboss::<i32>::new();
+    let job1 = manager.start(1, |_| std::future::pending::<()>()).unwrap();
+    let job1_2 = manager
+        .start_with_policy(1, ConflictPolicy::Queue, jobs::instant)
+        .unwrap();
+    // The new job only succeeds if both old jobs are finished by the time it
+    // begins.
+    let (first, second) = (job1.monitor().clone(), job1_2.monitor().clone());
+    let job1_3 = manager
+        .start_with_policy(1, ConflictPolicy::Replace, |_| async move {
+            first.is_finished() && second.is_finished()
+        })
+        .unwrap();
+    job1_3.wait().await.unwrap();
+    assert_eq!(job1.status().message(), "The job was cancelled");
+    assert_eq!(job1_2.status().message(), "The job was cancelled");
+    assert!(job1.monitor().finished_at() <= job1_2.monitor().finished_at());
+}
+
+#[tokio::test]
+async fn keep_history_policy_runs_jobs_alongside() {
+    let mut manager = Girlboss::<i32>::new();
+    let job1 = manager.start(1, jobs::slow).unwrap();
+    let job1_2 = manager
+        .start_with_policy(1, ConflictPolicy::KeepHistory, jobs::instant)
+        .unwrap();
+    job1_2.wait().await.unwrap();
+    assert!(!job1.is_finished());
+    assert_eq!(manager.get(&1), Some(job1_2));
+    assert_eq!(manager.history(&1), [job1]);
+    assert_eq!(manager.history(&2), []);
+}
+
+#[tokio::test]
+async fn cleanup_removes_finished_history() {
+    let mut manager = Girlboss::<i32>::new();
+    manager.set_conflict_policy(ConflictPolicy::KeepHistory);
+    let job1 = manager.start(1, jobs::instant).unwrap();
+    job1.wait().await.unwrap();
+    let job1_2 = manager.start(1, jobs::slow).unwrap();
+    assert_eq!(manager.history(&1), [job1]);
+    manager.cleanup(Duration::ZERO);
+    assert_eq!(manager.get(&1), Some(job1_2));
+    assert_eq!(manager.history(&1), []);
+}
+
+#[tokio::test]
+async fn cleanup_keeps_running_history() {
+    let mut manager = Girlboss::<i32>::new();
+    manager.set_conflict_policy(ConflictPolicy::KeepHistory);
+    let job1 = manager.start(1, jobs::instant).unwrap();
+    job1.wait().await.unwrap();
+    let job1_2 = manager.start(1, jobs::slow).unwrap();
+    let job1_3 = manager.start(1, jobs::instant).unwrap();
+    job1_3.wait().await.unwrap();
+
+    let evicted = Arc::new(Mutex::new(Vec::new()));
+    let evicted_2 = evicted.clone();
+    manager.on_event(move |event| {
+        if let Event::Evicted { monitor, .. } = event {
+            evicted_2.lock().unwrap().push(monitor.clone());
+        }
+    });
+    manager.cleanup(Duration::ZERO);
+    assert_eq!(manager.get(&1), Some(job1_2.clone()));
+    assert_eq!(manager.history(&1), []);
+    assert_eq!(
+        *evicted.lock().unwrap(),
+        [job1.monitor().clone(), job1_3.monitor().clone()],
+    );
+}
+
+#[tokio::test]
+async fn conflict_policy_applies_to_monitors() {
+    let mut manager = crate::Girlboss::<i32, Monitor>::new();
+    let job1 = manager.start::<Tokio, _, _>(1, jobs::slow).unwrap();
+    let result = manager.start::<Tokio, _, _>(1, jobs::slow);
+    assert_eq!(result, Err(Error::JobExists));
+    let job1_2 = manager
+        .start_with_policy::<Tokio, _, _>(1, ConflictPolicy::Replace, jobs::instant)
+        .unwrap();
+    job1_2.wait().await.unwrap();
+    assert_eq!(job1.status().message(), "The job was cancelled");
+}
+
+#[tokio::test]
+async fn history_limit_keeps_overwritten_jobs() {
+    let mut manager = Girlboss::<i32>::new();
+    manager.set_history_limit(2);
+    let mut started = Vec::new();
+    for _ in 0..4 {
+        let job = manager.start(1, jobs::fails).unwrap();
+        job.wait().await.unwrap_err();
+        started.push(job);
+    }
+    assert_eq!(manager.get(&1), Some(started[3].clone()));
+    assert_eq!(
+        manager.history(&1),
+        [started[2].clone(), started[1].clone()]
+    );
+}
+
+#[tokio::test]
+async fn history_limit_applies_to_keep_history_policy() {
+    let mut manager = Girlboss::<i32>::new();
+    manager.set_conflict_policy(ConflictPolicy::KeepHistory);
+    let job1 = manager.start(1, jobs::slow).unwrap();
+    let job1_2 = manager.start(1, jobs::slow).unwrap();
+    let job1_3 = manager.start(1, jobs::slow).unwrap();
+    assert_eq!(manager.history(&1), [job1_2.clone(), job1]);
+    manager.set_history_limit(1);
+    assert_eq!(manager.history(&1), [job1_2]);
+    assert_eq!(manager.get(&1), Some(job1_3));
+}
+
+#[tokio::test]
+async fn history_limit_applies_to_start_or_get() {
+    let mut manager = Girlboss::<i32>::new();
+    manager.set_history_limit(5);
+    let job1 = manager.start_or_get(1, jobs::instant);
+    job1.wait().await.unwrap();
+    let job1_2 = manager.start_or_get(1, jobs::instant);
+    assert_eq!(manager.get(&1), Some(job1_2));
+    assert_eq!(manager.history(&1), [job1]);
+}
+
+#[tokio::test]
+async fn start_blocking_queues_behind_running_job() {
+    let mut manager = Girlboss::<i32>::new();
+    manager.set_conflict_policy(ConflictPolicy::Queue);
+    let job1 = manager.start(1, jobs::slow).unwrap();
+    let previous = job1.monitor().clone();
+    let job1_2 = manager
+        .start_blocking(1, move |_| previous.is_finished())
+        .unwrap();
+    assert_eq!(manager.get(&1), Some(job1_2.clone()));
+    job1_2.wait().await.unwrap();
+}
+
+#[tokio::test]
+async fn start_blocking_works_with_monitors() {
+    let mut manager = crate::Girlboss::<i32, Monitor>::new();
+    let job = manager
+        .start_blocking::<Tokio, _, _>(1, |_| "Done")
+        .unwrap();
+    job.wait().await.unwrap();
+    assert_eq!(manager.get(&1).unwrap().status().message(), "Done");
+}
+
+fn background_runtime() -> tokio::runtime::Runtime {
+    tokio::runtime::Builder::new_multi_thread()
+        .thread_name("background")
+        .enable_time()
+        .build()
+        .unwrap()
+}
+
+fn thread_name(_: Monitor) -> Option<String> {
+    std::thread::current().name().map(str::to_owned)
+}
+
+#[tokio::test]
+async fn spawner_is_used_for_jobs() {
+    let background = background_runtime();
+    let mut manager = Girlboss::<i32>::new();
+    manager.set_spawner(background.handle().clone());
+    let job1 = manager
+        .start(1, |mon| async move { thread_name(mon) })
+        .unwrap();
+    let job2 = manager.start_blocking(2, thread_name).unwrap();
+    job1.wait().await.unwrap();
+    job2.wait().await.unwrap();
+    assert_eq!(job1.status().message(), "background");
+    assert_eq!(job2.status().message(), "background");
+    background.shutdown_background();
+}
+
+#[tokio::test]
+async fn spawner_is_used_with_monitors() {
+    let background = background_runtime();
+    let mut manager = crate::Girlboss::<i32, Monitor>::new();
+    manager.set_spawner::<Tokio>(background.handle().clone());
+    let job = manager
+        .start::<Tokio, _, _>(1, |mon| async move { thread_name(mon) })
+        .unwrap();
+    job.wait().await.unwrap();
+    assert_eq!(job.status().message(), "background");
+    background.shutdown_background();
+}
+
+#[cfg(feature = "actix-rt")]
+#[actix_rt::test]
+async fn spawners_of_different_runtimes_coexist() {
+    use crate::runtime::ActixRt;
+
+    let background = background_runtime();
+    let mut manager = crate::Girlboss::<i32, crate::common::DynJob>::new();
+    manager.set_spawner::<Tokio>(background.handle().clone());
+    manager.set_spawner::<ActixRt>(actix_rt::Arbiter::current());
+    let job1 = manager
+        .start::<Tokio, _, _>(1, |mon| async move { thread_name(mon) })
+        .unwrap();
+    let job2 = manager
+        .start::<ActixRt, _, _>(2, |_| async { "On this arbiter" })
+        .unwrap();
+    job1.wait().await.unwrap();
+    job2.wait().await.unwrap();
+    assert_eq!(job1.status().message(), "background");
+    assert_eq!(job2.status().message(), "On this arbiter");
+    background.shutdown_background();
+}
+
+#[tokio::test]
+async fn adopt_registers_task() {
+    let mut manager = Girlboss::<i32>::new();
+    let job = manager
+        .adopt(1, |mon| tokio::spawn(jobs::sets_status(mon)))
+        .unwrap();
+    assert_eq!(manager.get(&1), Some(job.clone()));
+    assert_eq!(
+        manager.adopt(1, |_| tokio::spawn(async {})),
+        Err(Error::JobExists)
+    );
+    job.wait().await.unwrap();
+    assert_eq!(job.status().message(), "Custom status");
+}
+
+#[tokio::test]
+async fn adopt_works_with_monitors() {
+    let mut manager = crate::Girlboss::<i32, Monitor>::new();
+    let job = manager
+        .adopt::<Tokio, _, _>(1, |_| tokio::spawn(async { "Adopted" }))
+        .unwrap();
+    job.wait().await.unwrap();
+    assert_eq!(manager.get(&1).unwrap().status().message(), "Adopted");
+}
@@ -0,0 +1,213 @@
+#![cfg(all(feature = "metrics", feature = "tokio"))]
+
+use std::time::Duration;
+
+use metrics_util::debugging::{DebugValue, DebuggingRecorder, Snapshotter};
+use tokio::sync::oneshot;
+
+use crate::tokio::{Girlboss, Job};
+use crate::ConflictPolicy;
+
+/// Runs `future` on a current-thread runtime, with `recorder` installed on
+/// this thread.
+fn run_with_recorder<F: std::future::Future>(recorder: &DebuggingRecorder, future: F) -> F::Output {
+    let runtime = tokio::runtime::Builder::new_current_thread()
+        .enable_time()
+        .build()
+        .unwrap();
+    metrics::with_local_recorder(recorder, || runtime.block_on(future))
+}
+
+/// Returns the recorded metrics as `name{labels} value` lines, sorted.
+fn snapshot(snapshotter: &Snapshotter) -> Vec<String> {
+    let mut lines: Vec<String> = snapshotter
+        .snapshot()
+        .into_vec()
+        .into_iter()
+        .map(|(key, _, _, value)| {
+            let labels: Vec<String> = key
+                .key()
+                .labels()
+                .map(|label| format!("{}={}", label.key(), label.value()))
+                .collect();
+            let value = match value {
+                DebugValue::Counter(value) => value.to_string(),
+                DebugValue::Gauge(value) => value.to_string(),
+                DebugValue::Histogram(values) => format!("{} samples", values.len()),
+            };
+            format!("{}{{{}}} {value}", key.key().name(), labels.join(","))
+        })
+        .collect();
+    lines.sort();
+    lines
+}
+
+#[test]
+fn records_job_metrics() {
+    let recorder = DebuggingRecorder::new();
+    let snapshotter = recorder.snapshotter();
+
+    run_with_recorder(&recorder, async {
+        let (sender, receiver) = oneshot::channel::<()>();
+        let blocked = Job::start(|_| async move {
+            receiver.await.unwrap();
+        });
+        tokio::task::yield_now().await;
+        assert_eq!(
+            snapshot(&snapshotter),
+            [
+                "girlboss_jobs_queued{} 0",
+                "girlboss_jobs_running{} 1",
+                "girlboss_jobs_started_total{} 1",
+            ],
+        );
+
+        sender.send(()).unwrap();
+        blocked.wait().await.unwrap();
+        Job::start(|_| async { Err::<(), _>("oops") })
+            .wait()
+            .await
+            .unwrap_err();
+    });
+
+    assert_eq!(
+        snapshot(&snapshotter),
+        [
+            "girlboss_job_duration_seconds{outcome=failed} 1 samples",
+            "girlboss_job_duration_seconds{outcome=succeeded} 1 samples",
+            "girlboss_jobs_finished_total{outcome=failed} 1",
+            "girlboss_jobs_finished_total{outcome=succeeded} 1",
+            "girlboss_jobs_queued{} 0",
+            "girlboss_jobs_running{} 0",
+            "girlboss_jobs_started_total{} 2",
+        ],
+    );
+}
+
+#[test]
+fn queued_jobs_are_not_running() {
+    let recorder = DebuggingRecorder::new();
+    let snapshotter = recorder.snapshotter();
+
+    run_with_recorder(&recorder, async {
+        let mut manager = Girlboss::<i32>::new();
+        manager.set_conflict_policy(ConflictPolicy::Queue);
+        let (sender, receiver) = oneshot::channel::<()>();
+        let first = manager
+            .start(1, |_| async move {
+                receiver.await.unwrap();
+            })
+            .unwrap();
+        let second = manager.start(1, |_| async {}).unwrap();
+        tokio::task::yield_now().await;
+
+        let stats = manager.stats();
+        assert_eq!((stats.running, stats.queued), (1, 1));
+        assert!(snapshot(&snapshotter).contains(&"girlboss_jobs_queued{} 1".to_owned()));
+
+        // Cancelling a queued job takes it straight out of the queue.
+        second.cancel();
+        sender.send(()).unwrap();
+        first.wait().await.unwrap();
+        second.wait().await.unwrap_err();
+
+        let stats = manager.stats();
+        assert_eq!((stats.running, stats.queued), (0, 0));
+        assert_eq!((stats.succeeded, stats.failed), (1, 1));
+    });
+}
+
+#[tokio::test]
+async fn manager_stats() {
+    let mut manager = Girlboss::<i32>::new();
+    manager
+        .start(1, |_| async {})
+        .unwrap()
+        .wait()
+        .await
+        .unwrap();
+    manager
+        .start(2, |_| async { Err::<(), _>("oops") })
+        .unwrap()
+        .wait()
+        .await
+        .unwrap_err();
+    manager
+        .start_blocking(3, |_| -> () { panic!("oops") })
+        .unwrap()
+        .wait()
+        .await
+        .unwrap_err();
+
+    // Removed jobs still count.
+    manager.cleanup(Duration::ZERO);
+
+    let stats = manager.stats();
+    assert_eq!(stats.started, 3);
+    assert_eq!(stats.succeeded, 1);
+    assert_eq!(stats.failed, 2);
+    assert_eq!(stats.finished(), 3);
+    assert_eq!((stats.running, stats.queued), (0, 0));
+    assert!(stats.duration_sum < Duration::from_secs(1));
+    assert_eq!(
+        stats.duration_buckets.first(),
+        Some(&(Duration::from_millis(100), 3)),
+    );
+    assert_eq!(
+        stats.duration_buckets.last(),
+        Some(&(Duration::from_secs(3600), 3)),
+    );
+}
+
+#[tokio::test]
+async fn render_prometheus() {
+    let mut manager = Girlboss::<i32>::new();
+    manager
+        .start(1, |_| async {})
+        .unwrap()
+        .wait()
+        .await
+        .unwrap();
+
+    let text = manager.render_prometheus();
+    let lines: Vec<&str> = text.lines().collect();
+    assert!(lines.contains(&"# TYPE girlboss_jobs_started_total counter"));
+    assert!(lines.contains(&"girlboss_jobs_started_total 1"));
+    assert!(lines.contains(&"girlboss_jobs_finished_total{outcome=\"succeeded\"} 1"));
+    assert!(lines.contains(&"girlboss_jobs_finished_total{outcome=\"failed\"} 0"));
+    assert!(lines.contains(&"# TYPE girlboss_jobs_running gauge"));
+    assert!(lines.contains(&"girlboss_jobs_running 0"));
+    assert!(lines.contains(&"girlboss_jobs_queued 0"));
+    assert!(lines.contains(&"# TYPE girlboss_job_duration_seconds histogram"));
+    assert!(lines.contains(&"girlboss_job_duration_seconds_bucket{le=\"0.1\"} 1"));
+    assert!(lines.contains(&"girlboss_job_duration_seconds_bucket{le=\"3600\"} 1"));
+    assert!(lines.contains(&"girlboss_job_duration_seconds_bucket{le=\"+Inf\"} 1"));
+    assert!(lines.contains(&"girlboss_job_duration_seconds_count 1"));
+    assert!(text.ends_with('\n'));
+}
+
+#[tokio::test]
+async fn stats_handle_reads_without_the_manager() {
+    let manager = std::sync::Arc::new(std::sync::Mutex::new(Girlboss::<i32>::new()));
+    let handle = manager.lock().unwrap().stats_handle();
+    let job = manager
+        .lock()
+        .unwrap()
+        .start(1, |_| async {
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        })
+        .unwrap();
+
+    // Reading the stats doesn't need the manager's lock.
+    let guard = manager.lock().unwrap();
+    assert_eq!(handle.clone().stats().started, 1);
+    drop(guard);
+
+    job.wait().await.unwrap();
+    assert_eq!(handle.stats(), manager.lock().unwrap().stats());
+    assert_eq!(handle.stats().succeeded, 1);
+    assert!(handle
+        .render_prometheus()
+        .lines()
+        .any(|line| line == "girlboss_jobs_started_total 1"));
+}
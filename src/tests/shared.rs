@@ -0,0 +1,494 @@
+//! Test cases that are shared by the async runtimes.
+
+#![cfg(any(feature = "tokio", feature = "smol"))]
+
+/// Generates a `shared` module with the test cases that every async runtime
+/// should pass.
+///
+/// The module that invokes this must provide:
+///
+/// * `Job`, the runtime's job type;
+/// * `block_on(future)`, which runs a future to completion on the runtime;
+/// * `sleep(duration)`, which waits using the runtime's timer;
+/// * `unblock(func)`, which runs `func` on a thread where blocking is fine;
+/// * `slow`, `sets_status`, `sets_status_with_write` and
+///   `sets_status_with_write_fmt`, like the ones in [`jobs`](super::jobs)
+///   but using the runtime's timer.
+macro_rules! shared_job_tests {
+    () => {
+        mod shared {
+            use std::sync::{Arc, Mutex};
+            use std::time::{Duration, Instant};
+
+            use super::{
+                block_on, sets_status, sets_status_with_write, sets_status_with_write_fmt, sleep,
+                slow, unblock, Job,
+            };
+            use $crate::tests::jobs;
+            use $crate::Error;
+
+            #[test]
+            fn debug_impl_makes_sense() {
+                block_on(async {
+                    let job = Job::start(jobs::instant);
+                    let repr = format!("{job:?}");
+                    assert!(repr.starts_with("Job("));
+                    assert!(repr.contains("0x"));
+                });
+            }
+
+            #[test]
+            fn pointer_impl_makes_sense() {
+                block_on(async {
+                    let job = Job::start(jobs::instant);
+                    let repr = format!("{job:p}");
+                    assert!(repr.starts_with("0x"));
+                });
+            }
+
+            #[test]
+            fn equals_self() {
+                block_on(async {
+                    let job = Job::start(slow);
+                    assert_eq!(job, job);
+                    assert_eq!(job, job.clone());
+                });
+            }
+
+            #[test]
+            fn does_not_equal_other() {
+                block_on(async {
+                    let job1 = Job::start(slow);
+                    let job2 = Job::start(slow);
+                    assert_ne!(job1, job2);
+                });
+            }
+
+            #[test]
+            fn sets_default_status() {
+                block_on(async {
+                    let job = Job::start(jobs::instant);
+                    assert_eq!(job.status().message(), "Starting job");
+                });
+            }
+
+            #[test]
+            fn sets_custom_status() {
+                block_on(async {
+                    let job = Job::start(sets_status);
+                    sleep(Duration::from_millis(50)).await;
+                    assert_eq!(job.status().message(), "Custom status");
+                });
+            }
+
+            #[test]
+            fn sets_custom_status_with_write() {
+                block_on(async {
+                    let job = Job::start(sets_status_with_write);
+                    sleep(Duration::from_millis(50)).await;
+                    assert_eq!(job.status().message(), "trans rights");
+                });
+            }
+
+            #[test]
+            fn sets_custom_status_with_write_fmt() {
+                block_on(async {
+                    let job = Job::start(sets_status_with_write_fmt);
+                    sleep(Duration::from_millis(50)).await;
+                    assert_eq!(job.status().message(), "tends to 42");
+                });
+            }
+
+            #[test]
+            fn sets_custom_status_by_return_value() {
+                block_on(async {
+                    let job = Job::start(jobs::sets_status_by_return);
+                    job.wait().await.unwrap();
+                    assert_eq!(job.status().message(), "Custom status by return");
+                });
+            }
+
+            #[test]
+            fn outcome_is_none_when_in_progress() {
+                block_on(async {
+                    let job = Job::start(slow);
+                    assert_eq!(job.outcome(), None);
+                    assert!(!job.succeeded());
+                });
+            }
+
+            #[test]
+            fn outcome_is_false_when_failed() {
+                block_on(async {
+                    let job = Job::start(jobs::fails);
+                    assert_eq!(job.wait().await, Err(Error::JobFailed));
+                    assert_eq!(job.outcome(), Some(false));
+                    assert!(!job.succeeded());
+                    assert_eq!(job.status().message(), "oopsie");
+                });
+            }
+
+            #[test]
+            fn panic_is_caught() {
+                block_on(async {
+                    let job = Job::start(jobs::panics);
+                    assert_eq!(job.wait().await, Err(Error::JobFailed));
+                    assert_eq!(job.outcome(), Some(false));
+                    assert!(!job.succeeded());
+                    assert_eq!(job.status().message(), "The job panicked");
+                });
+            }
+
+            #[test]
+            fn outcome_is_true_when_succeeded() {
+                block_on(async {
+                    let job = Job::start(jobs::instant);
+                    job.wait().await.unwrap();
+                    assert_eq!(job.outcome(), Some(true));
+                    assert!(job.succeeded());
+                });
+            }
+
+            #[test]
+            fn started_time_makes_sense() {
+                block_on(async {
+                    let before = Instant::now();
+                    let job = Job::start(slow);
+                    let after = Instant::now();
+                    assert!(before <= job.monitor().started_at());
+                    assert!(job.monitor().started_at() <= after);
+                });
+            }
+
+            #[test]
+            fn finished_time_makes_sense() {
+                block_on(async {
+                    let job = Job::start(slow);
+                    sleep(Duration::from_millis(50)).await;
+                    let before = Instant::now();
+                    job.wait().await.unwrap();
+                    let after = Instant::now();
+                    let finished_at = job.monitor().finished_at().unwrap();
+                    assert!(before <= finished_at);
+                    assert!(finished_at <= after);
+                });
+            }
+
+            #[test]
+            fn elapsed_time_makes_sense() {
+                block_on(async {
+                    let job = Job::start(slow);
+                    job.wait().await.unwrap();
+                    assert!(job.monitor().elapsed() >= Duration::from_millis(100));
+                    assert!(job.monitor().elapsed() <= Duration::from_millis(150));
+                });
+            }
+
+            #[test]
+            fn elapsed_time_is_retained_after_finish() {
+                block_on(async {
+                    let job = Job::start(slow);
+                    job.wait().await.unwrap();
+                    sleep(Duration::from_millis(200)).await;
+                    assert!(job.monitor().elapsed() >= Duration::from_millis(100));
+                    assert!(job.monitor().elapsed() <= Duration::from_millis(150));
+                });
+            }
+
+            #[test]
+            fn elapsed_time_makes_sense_before_finish() {
+                block_on(async {
+                    let job = Job::start(slow);
+                    assert!(job.monitor().elapsed() <= Duration::from_millis(50));
+                });
+            }
+
+            #[test]
+            fn is_finished_is_correct() {
+                block_on(async {
+                    let job = Job::start(slow);
+                    assert!(!job.is_finished());
+                    sleep(Duration::from_millis(150)).await;
+                    assert!(job.is_finished());
+                    job.wait().await.unwrap();
+                    assert!(job.is_finished());
+                });
+            }
+
+            #[test]
+            fn cancel_stops_job() {
+                block_on(async {
+                    let job = Job::start(slow);
+                    job.cancel();
+                    assert_eq!(job.wait().await, Err(Error::JobFailed));
+                    assert_eq!(job.outcome(), Some(false));
+                    assert_eq!(job.status().message(), "The job was cancelled");
+                    assert!(job.monitor().elapsed() < Duration::from_millis(100));
+                });
+            }
+
+            #[test]
+            fn cancel_does_not_affect_finished_job() {
+                block_on(async {
+                    let job = Job::start(jobs::sets_status_by_return);
+                    job.wait().await.unwrap();
+                    job.cancel();
+                    job.wait().await.unwrap();
+                    assert_eq!(job.outcome(), Some(true));
+                    assert_eq!(job.status().message(), "Custom status by return");
+                });
+            }
+
+            #[test]
+            fn wait_timeout_gives_up_on_slow_job() {
+                block_on(async {
+                    let job = Job::start(slow);
+                    let result = job.wait_timeout(Duration::from_millis(10)).await;
+                    assert_eq!(result, Err(Error::Timeout));
+                    assert!(!job.is_finished());
+                });
+            }
+
+            #[test]
+            fn wait_timeout_returns_job_result() {
+                block_on(async {
+                    let job = Job::start(jobs::fails);
+                    let result = job.wait_timeout(Duration::from_millis(100)).await;
+                    assert_eq!(result, Err(Error::JobFailed));
+                });
+            }
+
+            #[test]
+            fn job_can_be_awaited() {
+                block_on(async {
+                    let job = Job::start(jobs::sets_status_by_return);
+                    (&job).await.unwrap();
+                    assert_eq!(job.status().message(), "Custom status by return");
+                    assert_eq!(Job::start(jobs::panics).await, Err(Error::JobFailed));
+                });
+            }
+
+            #[test]
+            fn future_works_with_select() {
+                block_on(async {
+                    let slow = Job::start(slow);
+                    let fails = Job::start(jobs::fails);
+                    let mut slow_future = slow.future();
+                    let mut fails_future = fails.future();
+                    let first = futures::select! {
+                        result = slow_future => ("slow", result),
+                        result = fails_future => ("fails", result),
+                    };
+                    assert_eq!(first, ("fails", Err(Error::JobFailed)));
+                    assert_eq!(slow_future.await, Ok(()));
+                });
+            }
+
+            #[test]
+            fn future_can_be_cloned() {
+                block_on(async {
+                    let job = Job::start(slow);
+                    let future = job.future();
+                    let (a, b) = futures::join!(future.clone(), future);
+                    assert_eq!((a, b), (Ok(()), Ok(())));
+                });
+            }
+
+            #[test]
+            fn monitor_finished_resolves_to_outcome() {
+                block_on(async {
+                    let job = Job::start(jobs::fails);
+                    assert!(!job.monitor().finished().await);
+                    let job = Job::start(slow);
+                    assert!(job.monitor().finished().await);
+                    assert!(job.monitor().finished().await);
+                });
+            }
+
+            #[test]
+            fn monitor_finished_works_outside_runtime() {
+                block_on(async {
+                    let job = Job::start(slow);
+                    let finished = job.monitor().finished();
+                    let thread = std::thread::spawn(|| futures::executor::block_on(finished));
+                    job.wait().await.unwrap();
+                    assert!(thread.join().unwrap());
+                });
+            }
+
+            #[test]
+            fn dropped_finished_futures_stop_waiting() {
+                block_on(async {
+                    let job = Job::start(slow);
+                    for _ in 0..3 {
+                        let mut finished = job.monitor().finished();
+                        assert!(futures::poll!(&mut finished).is_pending());
+                        assert!(futures::poll!(&mut finished).is_pending());
+                        assert_eq!(job.monitor().waiter_count(), 1);
+                    }
+                    assert_eq!(job.monitor().waiter_count(), 0);
+                    job.wait().await.unwrap();
+                });
+            }
+
+            /// Registers an `on_finish` callback that records what it was called with.
+            fn record_finish(job: &Job) -> Arc<Mutex<Vec<(bool, String)>>> {
+                let calls = Arc::new(Mutex::new(Vec::new()));
+                let calls_2 = calls.clone();
+                job.on_finish(move |outcome, status| {
+                    calls_2
+                        .lock()
+                        .unwrap()
+                        .push((outcome, status.message().to_owned()));
+                });
+                calls
+            }
+
+            #[test]
+            fn on_finish_runs_when_job_finishes() {
+                block_on(async {
+                    let job = Job::start(jobs::fails);
+                    let calls = record_finish(&job);
+                    job.wait().await.unwrap_err();
+                    assert_eq!(*calls.lock().unwrap(), [(false, "oopsie".to_owned())]);
+                });
+            }
+
+            #[test]
+            fn on_finish_runs_when_job_panics() {
+                block_on(async {
+                    let job = Job::start(jobs::panics);
+                    let calls = record_finish(&job);
+                    job.wait().await.unwrap_err();
+                    assert_eq!(
+                        *calls.lock().unwrap(),
+                        [(false, "The job panicked".to_owned())]
+                    );
+                });
+            }
+
+            #[test]
+            fn on_finish_runs_immediately_for_finished_job() {
+                block_on(async {
+                    let job = Job::start(jobs::sets_status_by_return);
+                    job.wait().await.unwrap();
+                    let calls = record_finish(&job);
+                    assert_eq!(
+                        *calls.lock().unwrap(),
+                        [(true, "Custom status by return".to_owned())],
+                    );
+                });
+            }
+
+            #[test]
+            fn on_finish_panics_are_contained() {
+                block_on(async {
+                    let job = Job::start(slow);
+                    job.on_finish(|_, _| panic!("first callback"));
+                    let calls = record_finish(&job);
+                    job.wait().await.unwrap();
+                    assert_eq!(calls.lock().unwrap().len(), 1);
+
+                    // The callback runs right away here, so the panic would
+                    // otherwise reach the caller.
+                    job.on_finish(|_, _| panic!("late callback"));
+                    assert_eq!(record_finish(&job).lock().unwrap().len(), 1);
+                });
+            }
+
+            #[test]
+            fn then_starts_after_job_finishes() {
+                block_on(async {
+                    let job = Job::start(slow);
+                    let previous = job.monitor().clone();
+                    let next = job.then(|_| async move { previous.is_finished() });
+                    next.wait().await.unwrap();
+                    assert!(job.is_finished());
+                });
+            }
+
+            #[test]
+            fn wait_still_works_after_wait_timeout() {
+                block_on(async {
+                    let job = Job::start(slow);
+                    let result = job.wait_timeout(Duration::from_millis(10)).await;
+                    assert_eq!(result, Err(Error::Timeout));
+                    job.wait().await.unwrap();
+                    assert!(job.is_finished());
+                });
+            }
+
+            #[test]
+            fn wait_blocking_waits_for_job() {
+                block_on(async {
+                    let job = Job::start(slow);
+                    let monitor = job.monitor().clone();
+                    let result = unblock(move || monitor.wait_blocking());
+                    assert_eq!(result.await, Ok(()));
+                    assert!(job.is_finished());
+
+                    let job = Job::start(jobs::fails);
+                    let result = unblock(move || job.monitor().wait_blocking());
+                    assert_eq!(result.await, Err(Error::JobFailed));
+                });
+            }
+
+            #[test]
+            fn wait_blocking_timeout_gives_up() {
+                block_on(async {
+                    let job = Job::start(slow);
+                    let monitor = job.monitor().clone();
+                    let result =
+                        unblock(move || monitor.wait_blocking_timeout(Duration::from_millis(10)));
+                    assert_eq!(result.await, Err(Error::Timeout));
+                    assert!(!job.is_finished());
+                });
+            }
+
+            #[test]
+            fn wait_blocking_panics_inside_job() {
+                block_on(async {
+                    let slow = Job::start(slow);
+                    let monitor = slow.monitor().clone();
+                    let job = Job::start(|_| async move { monitor.wait_blocking() });
+                    assert_eq!(job.wait().await, Err(Error::JobFailed));
+                    assert_eq!(job.status().message(), "The job panicked");
+                    assert!(!slow.is_finished());
+                });
+            }
+
+            #[test]
+            fn start_blocking_reports_status() {
+                block_on(async {
+                    let job = Job::start_blocking(|mon| {
+                        mon.report("Working");
+                        std::thread::sleep(Duration::from_millis(50));
+                        "Done"
+                    });
+                    assert!(!job.is_finished());
+                    job.wait().await.unwrap();
+                    assert_eq!(job.status().message(), "Done");
+                });
+            }
+
+            #[test]
+            fn start_blocking_catches_panics() {
+                block_on(async {
+                    fn panics(_: $crate::Monitor) {
+                        panic!("uh oh");
+                    }
+
+                    let job = Job::start_blocking(panics);
+                    assert_eq!(job.wait().await, Err(Error::JobFailed));
+                    assert_eq!(job.status().message(), "The job panicked");
+
+                    let job = Job::start_blocking(|_| Err::<(), _>("oopsie"));
+                    assert_eq!(job.wait().await, Err(Error::JobFailed));
+                    assert_eq!(job.status().message(), "oopsie");
+                });
+            }
+        }
+    };
+}
+
+pub(crate) use shared_job_tests;
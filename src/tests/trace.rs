@@ -0,0 +1,123 @@
+#![cfg(all(feature = "tracing", feature = "tokio"))]
+
+use std::fmt;
+use std::sync::{Arc, Mutex};
+
+use tracing::field::{Field, Visit};
+use tracing::span::{Attributes, Id, Record};
+use tracing::{Event, Subscriber};
+use tracing_subscriber::layer::{Context, Layer, SubscriberExt};
+use tracing_subscriber::registry::LookupSpan;
+
+use crate::tokio::Girlboss;
+#[cfg(feature = "tracing-subscriber")]
+use crate::tokio::Job;
+#[cfg(feature = "tracing-subscriber")]
+use crate::trace::MonitorLayer;
+
+/// A layer that records the fields of all events and job spans as strings.
+#[derive(Clone, Default)]
+struct Recorder(Arc<Mutex<Vec<String>>>);
+
+struct FieldsVisitor(String);
+
+impl Visit for FieldsVisitor {
+    fn record_debug(&mut self, field: &Field, value: &dyn fmt::Debug) {
+        if !self.0.is_empty() {
+            self.0.push(' ');
+        }
+        self.0.push_str(&format!("{}={value:?}", field.name()));
+    }
+}
+
+impl<S: Subscriber + for<'a> LookupSpan<'a>> Layer<S> for Recorder {
+    fn on_new_span(&self, attrs: &Attributes<'_>, _id: &Id, _ctx: Context<'_, S>) {
+        let mut visitor = FieldsVisitor(format!("new span {}:", attrs.metadata().name()));
+        attrs.record(&mut visitor);
+        self.0.lock().unwrap().push(visitor.0);
+    }
+
+    fn on_record(&self, _id: &Id, values: &Record<'_>, _ctx: Context<'_, S>) {
+        let mut visitor = FieldsVisitor("record:".into());
+        values.record(&mut visitor);
+        self.0.lock().unwrap().push(visitor.0);
+    }
+
+    fn on_event(&self, event: &Event<'_>, ctx: Context<'_, S>) {
+        let span = ctx.event_span(event).map(|span| span.name());
+        let mut visitor = FieldsVisitor(format!("{} in {span:?}:", event.metadata().level()));
+        event.record(&mut visitor);
+        self.0.lock().unwrap().push(visitor.0);
+    }
+}
+
+#[tokio::test]
+async fn emits_span_and_events() {
+    let recorder = Recorder::default();
+    let subscriber = tracing_subscriber::registry().with(recorder.clone());
+    let _guard = tracing::subscriber::set_default(subscriber);
+
+    let mut manager = Girlboss::<&str>::new();
+    manager.record_span_ids();
+    // Calling it again mustn't record the ID twice.
+    manager.record_span_ids();
+    let job = manager
+        .start("import", |mon| async move {
+            write!(mon, "Importing");
+            Err::<(), _>("Out of coffee")
+        })
+        .unwrap();
+    job.wait().await.unwrap_err();
+
+    let records = recorder.0.lock().unwrap();
+    let mut records = records.iter().map(|record| {
+        // The elapsed time varies.
+        record.split(" elapsed=").next().unwrap()
+    });
+    assert_eq!(records.next(), Some("new span job:"));
+    assert_eq!(records.next(), Some("record: id=\"import\""));
+    assert_eq!(
+        records.next(),
+        Some("DEBUG in Some(\"job\"): message=job reported a status status=\"Importing\""),
+    );
+    assert_eq!(
+        records.next(),
+        Some("DEBUG in Some(\"job\"): message=job reported a status status=\"Out of coffee\""),
+    );
+    assert_eq!(
+        records.next(),
+        Some("WARN in Some(\"job\"): message=job finished succeeded=false"),
+    );
+    assert_eq!(records.next(), None);
+}
+
+#[cfg(feature = "tracing-subscriber")]
+#[tokio::test]
+async fn layer_reports_events_inside_jobs() {
+    let subscriber = tracing_subscriber::registry()
+        .with(MonitorLayer::new().with_max_level(tracing::Level::DEBUG));
+    let _guard = tracing::subscriber::set_default(subscriber);
+
+    tracing::info!("Not inside a job");
+    let job = Job::start(|_| async {
+        tracing::info!("Downloading");
+        tracing::debug!(bytes = 42, "Downloaded");
+        tracing::trace!("Too detailed");
+    });
+    job.wait().await.unwrap();
+    assert_eq!(job.status().message(), "Downloaded");
+}
+
+#[cfg(feature = "tracing-subscriber")]
+#[tokio::test]
+async fn layer_ignores_events_outside_job_span() {
+    let subscriber = tracing_subscriber::registry().with(MonitorLayer::new());
+    let _guard = tracing::subscriber::set_default(subscriber);
+
+    let other_span = tracing::info_span!("other");
+    let job = Job::start(move |_| async move {
+        tracing::info!(parent: &other_span, "Somewhere else");
+    });
+    job.wait().await.unwrap();
+    assert_eq!(job.status().message(), "Starting job");
+}
@@ -1,6 +1,8 @@
 #[tokio::test]
 async fn general() {
     is_send_sync::<crate::Monitor>();
+    is_send_sync::<crate::common::JobFuture>();
+    is_send_sync::<crate::common::DynJob>();
 }
 
 #[cfg(feature = "tokio")]
@@ -34,4 +36,22 @@ async fn actix_rt() {
     });
 }
 
+#[cfg(feature = "tokio-local")]
+#[tokio::test]
+async fn tokio_local() {
+    use crate::runtime::{Spawnable, TokioLocal};
+
+    fn value_is_spawnable<T: Spawnable<TokioLocal>>(_: T) {}
+
+    is_send_sync::<crate::tokio_local::Girlboss<i32>>();
+    is_send_sync::<crate::tokio_local::Job>();
+
+    // should be able to spawn non-Send non-Sync futures
+    value_is_spawnable(async {
+        let raw_ptr = &() as *const _;
+        async {}.await;
+        println!("{raw_ptr:?}");
+    });
+}
+
 fn is_send_sync<T: Send + Sync>() {}
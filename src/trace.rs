@@ -0,0 +1,66 @@
+//! Integration with [`tracing`].
+//!
+//! With the `tracing` feature, every job gets a span named `job`, which is
+//! entered while the job runs. The span's parent is the span in which the job
+//! was started, and a job manager can record job IDs in it using
+//! [`Girlboss::record_span_ids`](crate::Girlboss::record_span_ids). A `DEBUG`
+//! event is emitted in the span for every status report, and an `INFO` event
+//! (or a `WARN` event, if the job failed) is emitted once the job finishes,
+//! with its outcome and duration. All of these use the `girlboss` target.
+//!
+//! Events can also go the other way: with the `tracing-subscriber` feature,
+//! `MonitorLayer` reports the events that happen inside a job to the job's
+//! [`Monitor`].
+
+use tracing::Span;
+
+use crate::{JobStatus, Monitor};
+
+#[cfg(feature = "tracing-subscriber")]
+mod layer;
+
+#[cfg(feature = "tracing-subscriber")]
+pub use layer::MonitorLayer;
+
+/// The target of the spans and events that this crate creates.
+const TARGET: &str = "girlboss";
+
+/// Creates the span of a new job.
+pub(crate) fn job_span() -> Span {
+    tracing::info_span!(target: TARGET, "job", id = tracing::field::Empty)
+}
+
+/// Emits the event for a status report.
+pub(crate) fn reported(monitor: &Monitor, status: &JobStatus) {
+    tracing::debug!(
+        target: TARGET,
+        parent: monitor.span(),
+        status = status.message(),
+        "job reported a status",
+    );
+}
+
+/// Emits the event for a finished job.
+pub(crate) fn finished(monitor: &Monitor) {
+    let status = monitor.status();
+    let elapsed = monitor.elapsed();
+    if monitor.succeeded() {
+        tracing::info!(
+            target: TARGET,
+            parent: monitor.span(),
+            succeeded = true,
+            ?elapsed,
+            status = status.message(),
+            "job finished",
+        );
+    } else {
+        tracing::warn!(
+            target: TARGET,
+            parent: monitor.span(),
+            succeeded = false,
+            ?elapsed,
+            status = status.message(),
+            "job finished",
+        );
+    }
+}
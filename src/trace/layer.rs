@@ -0,0 +1,111 @@
+use std::fmt;
+
+use tracing::field::{Field, Visit};
+use tracing::{Event, Level, Subscriber};
+use tracing_subscriber::layer::{Context, Layer};
+use tracing_subscriber::registry::LookupSpan;
+
+use super::TARGET;
+use crate::Monitor;
+
+/// A [`Layer`] that reports the events that happen inside a job to the job's
+/// [`Monitor`], using the event's message as the job's status.
+///
+/// By default, events at the `INFO` level or above are reported. This
+/// crate's own events are never reported.
+///
+/// # Examples
+///
+/// ```
+/// # #[tokio::main]
+/// # async fn main() {
+/// use girlboss::tokio::Job;
+/// use girlboss::trace::MonitorLayer;
+/// use tracing_subscriber::layer::SubscriberExt;
+///
+/// let subscriber = tracing_subscriber::registry().with(MonitorLayer::new());
+/// tracing::subscriber::set_global_default(subscriber).unwrap();
+///
+/// let job = Job::start(|_| async {
+///     tracing::info!("Downloading");
+///     tracing::debug!("This is too detailed to be reported");
+/// });
+/// job.wait().await.unwrap();
+/// assert_eq!(job.status().message(), "Downloading");
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct MonitorLayer {
+    max_level: Level,
+}
+
+impl MonitorLayer {
+    /// Creates a layer that reports events at the `INFO` level or above.
+    pub fn new() -> Self {
+        MonitorLayer {
+            max_level: Level::INFO,
+        }
+    }
+
+    /// Reports events at `max_level` or above, instead of `INFO` or above.
+    pub fn with_max_level(mut self, max_level: Level) -> Self {
+        self.max_level = max_level;
+        self
+    }
+}
+
+impl Default for MonitorLayer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<S> Layer<S> for MonitorLayer
+where
+    S: Subscriber + for<'a> LookupSpan<'a>,
+{
+    fn on_event(&self, event: &Event<'_>, ctx: Context<'_, S>) {
+        let metadata = event.metadata();
+        if *metadata.level() > self.max_level || metadata.target() == TARGET {
+            return;
+        }
+        let Some(monitor) = Monitor::current() else {
+            return;
+        };
+
+        // Only report events that happen inside the job's span, and not, for
+        // example, events with an explicit parent outside of the job.
+        let Some(job_span) = monitor.span().id() else {
+            return;
+        };
+        let Some(mut scope) = ctx.event_scope(event) else {
+            return;
+        };
+        if !scope.any(|span| span.id() == job_span) {
+            return;
+        }
+
+        let mut visitor = MessageVisitor(None);
+        event.record(&mut visitor);
+        if let Some(message) = visitor.0 {
+            monitor.report(message);
+        }
+    }
+}
+
+/// Extracts the message of an event.
+struct MessageVisitor(Option<String>);
+
+impl Visit for MessageVisitor {
+    fn record_str(&mut self, field: &Field, value: &str) {
+        if field.name() == "message" {
+            self.0 = Some(value.to_owned());
+        }
+    }
+
+    fn record_debug(&mut self, field: &Field, value: &dyn fmt::Debug) {
+        if field.name() == "message" {
+            self.0 = Some(format!("{value:?}"));
+        }
+    }
+}